@@ -0,0 +1,403 @@
+//! Automatic placement of newly opened windows
+//!
+//! Takes windows reported by a `WindowEventSource` and matches them
+//! against the user's auto-place rules. Windows often appear before their
+//! size or title is final, so windows that are not ready yet are retried
+//! with exponential backoff until they are or the retries run out.
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use crate::config::window_rules::{AutoPlaceRule, auto_place_rule_for};
+use crate::platform::window::WindowInfo;
+use crate::platform::window_events::WindowEventSource;
+
+/// Number of placed windows remembered before the set is reset
+const MAX_PLACED_WINDOWS: usize = 1024;
+
+/// Backoff schedule for windows that are not ready to be placed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first check
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Multiplier applied to the delay after each retry
+    pub factor: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(50),
+            factor: 2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the delay before a retry
+    ///
+    /// # Arguments
+    /// * `attempt` - Zero-based retry number
+    ///
+    /// # Returns
+    /// The delay, or None if no retries are left
+    pub fn delay_for(&self, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        Some(self.initial_delay * self.factor.saturating_pow(attempt))
+    }
+}
+
+/// Window waiting for a retry
+#[derive(Debug, Clone, Copy)]
+struct PendingWindow {
+    handle: isize,
+    attempt: u32,
+    next_try: Instant,
+}
+
+/// Matches newly shown windows against auto-place rules
+///
+/// # Example
+/// ```ignore
+/// let mut placer = AutoPlacer::new(RetryPolicy::default());
+/// let shown = source.poll_shown();
+/// for (window, rule_index) in placer.process(&shown, &source, &config.auto_place, Instant::now()) {
+///     // place `window` according to `config.auto_place[rule_index]`
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct AutoPlacer {
+    policy: RetryPolicy,
+    pending: Vec<PendingWindow>,
+    /// Windows already placed, so showing them again does not move them back
+    placed: HashSet<isize>,
+}
+
+impl AutoPlacer {
+    /// Creates an auto placer with the given retry policy
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self {
+            policy,
+            pending: Vec::new(),
+            placed: HashSet::new(),
+        }
+    }
+
+    /// Returns true if windows are waiting for a retry
+    #[cfg(test)]
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Forgets windows that were destroyed
+    ///
+    /// Window handles are reused, so a new window getting the handle of a
+    /// placed one must be placed again.
+    ///
+    /// # Arguments
+    /// * `destroyed` - Handles of windows destroyed since the last call
+    pub fn forget(&mut self, destroyed: &[isize]) {
+        for handle in destroyed {
+            self.placed.remove(handle);
+        }
+        self.pending.retain(|p| !destroyed.contains(&p.handle));
+    }
+
+    /// Checks new and pending windows
+    ///
+    /// # Arguments
+    /// * `shown` - Handles of windows shown since the last call
+    /// * `source` - Source to look up window information from
+    /// * `rules` - Auto-place rules, in priority order
+    /// * `now` - Current time, for retry scheduling
+    ///
+    /// # Returns
+    /// The windows to place now, each with the index of its matching rule
+    pub fn process(
+        &mut self,
+        shown: &[isize],
+        source: &impl WindowEventSource,
+        rules: &[AutoPlaceRule],
+        now: Instant,
+    ) -> Vec<(WindowInfo, usize)> {
+        if rules.is_empty() {
+            self.pending.clear();
+            return Vec::new();
+        }
+
+        let mut candidates: Vec<PendingWindow> = Vec::new();
+        let mut waiting = Vec::new();
+        for pending in self.pending.drain(..) {
+            if pending.next_try <= now {
+                candidates.push(pending);
+            } else {
+                waiting.push(pending);
+            }
+        }
+        for &handle in shown {
+            let known = self.placed.contains(&handle)
+                || waiting
+                    .iter()
+                    .chain(&candidates)
+                    .any(|p| p.handle == handle);
+            if !known {
+                candidates.push(PendingWindow {
+                    handle,
+                    attempt: 0,
+                    next_try: now,
+                });
+            }
+        }
+        self.pending = waiting;
+
+        let mut ready = Vec::new();
+        for candidate in candidates {
+            // The window was closed in the meantime
+            let Some(info) = source.window_info(candidate.handle) else {
+                continue;
+            };
+
+            if !is_ready(&info) {
+                if let Some(delay) = self.policy.delay_for(candidate.attempt) {
+                    self.pending.push(PendingWindow {
+                        handle: candidate.handle,
+                        attempt: candidate.attempt + 1,
+                        next_try: now + delay,
+                    });
+                }
+                continue;
+            }
+
+            if let Some(rule_index) = auto_place_rule_for(rules, &info) {
+                if self.placed.len() >= MAX_PLACED_WINDOWS {
+                    self.placed.clear();
+                }
+                self.placed.insert(candidate.handle);
+                ready.push((info, rule_index));
+            }
+        }
+
+        ready
+    }
+}
+
+/// Returns true once a window has a size and a title to match rules against
+fn is_ready(info: &WindowInfo) -> bool {
+    info.rect.w > 0 && info.rect.h > 0 && !info.title.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::settings::GridConfig;
+    use crate::config::window_rules::{KeyRange, WindowMatch};
+    use crate::domain::core::Rect;
+    use std::collections::HashMap;
+    use windows::Win32::Foundation::HWND;
+
+    /// Event source with scripted windows
+    #[derive(Default)]
+    struct FakeSource {
+        shown: Vec<isize>,
+        destroyed: Vec<isize>,
+        windows: HashMap<isize, WindowInfo>,
+    }
+
+    impl FakeSource {
+        fn show(&mut self, handle: isize, process: &str, rect: Rect) {
+            self.shown.push(handle);
+            self.windows.insert(handle, window(handle, process, rect));
+        }
+
+        fn destroy(&mut self, handle: isize) {
+            self.destroyed.push(handle);
+            self.windows.remove(&handle);
+        }
+    }
+
+    impl WindowEventSource for FakeSource {
+        fn poll_shown(&mut self) -> Vec<isize> {
+            std::mem::take(&mut self.shown)
+        }
+
+        fn poll_closed(&mut self) -> Vec<isize> {
+            self.destroyed.clone()
+        }
+
+        fn poll_destroyed(&mut self) -> Vec<isize> {
+            std::mem::take(&mut self.destroyed)
+        }
+
+        fn window_info(&self, handle: isize) -> Option<WindowInfo> {
+            self.windows.get(&handle).cloned()
+        }
+    }
+
+    fn window(handle: isize, process: &str, rect: Rect) -> WindowInfo {
+        WindowInfo {
+            handle: HWND(handle),
+            title: "Untitled".to_string(),
+            class_name: "Window".to_string(),
+            process_name: process.to_string(),
+            rect,
+            normal_rect: rect,
+            is_resizable: true,
+            has_caption: true,
+            is_child: false,
+            is_maximized: false,
+        }
+    }
+
+    fn process(
+        placer: &mut AutoPlacer,
+        source: &mut FakeSource,
+        rules: &[AutoPlaceRule],
+        now: Instant,
+    ) -> Vec<(WindowInfo, usize)> {
+        let shown = source.poll_shown();
+        placer.forget(&source.poll_destroyed());
+        placer.process(&shown, source, rules, now)
+    }
+
+    fn rules() -> Vec<AutoPlaceRule> {
+        vec![AutoPlaceRule {
+            criteria: WindowMatch {
+                process: Some("slack.exe".to_string()),
+                ..WindowMatch::default()
+            },
+            monitor: None,
+            layout: GridConfig::new(3, 2),
+            keys: KeyRange::new('E', 'D'),
+        }]
+    }
+
+    const SIZED: Rect = Rect {
+        x: 100,
+        y: 100,
+        w: 800,
+        h: 600,
+    };
+    const EMPTY: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 0,
+        h: 0,
+    };
+
+    #[test]
+    fn retry_delays_grow_exponentially() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(50),
+            factor: 2,
+        };
+        assert_eq!(policy.delay_for(0), Some(Duration::from_millis(50)));
+        assert_eq!(policy.delay_for(1), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay_for(2), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay_for(3), None);
+    }
+
+    #[test]
+    fn matching_windows_are_placed_once() {
+        let mut source = FakeSource::default();
+        let mut placer = AutoPlacer::new(RetryPolicy::default());
+        let rules = rules();
+        let now = Instant::now();
+
+        source.show(1, "slack.exe", SIZED);
+        source.show(2, "code.exe", SIZED);
+        let ready = process(&mut placer, &mut source, &rules, now);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].0.handle, HWND(1));
+        assert_eq!(ready[0].1, 0);
+        assert!(!placer.has_pending());
+
+        // Showing the window again (e.g. restored from the tray) leaves it alone
+        source.shown.push(1);
+        assert!(process(&mut placer, &mut source, &rules, now).is_empty());
+    }
+
+    #[test]
+    fn windows_without_size_are_retried() {
+        let mut source = FakeSource::default();
+        let mut placer = AutoPlacer::new(RetryPolicy::default());
+        let rules = rules();
+        let start = Instant::now();
+
+        source.show(1, "slack.exe", EMPTY);
+        assert!(process(&mut placer, &mut source, &rules, start).is_empty());
+        assert!(placer.has_pending());
+
+        // Not due yet
+        source.windows.get_mut(&1).unwrap().rect = SIZED;
+        assert!(process(&mut placer, &mut source, &rules, start).is_empty());
+
+        let later = start + Duration::from_millis(50);
+        let ready = process(&mut placer, &mut source, &rules, later);
+        assert_eq!(ready.len(), 1);
+        assert!(!placer.has_pending());
+    }
+
+    #[test]
+    fn retries_stop_when_exhausted_or_window_closed() {
+        let mut source = FakeSource::default();
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            initial_delay: Duration::from_millis(10),
+            factor: 2,
+        };
+        let mut placer = AutoPlacer::new(policy);
+        let rules = rules();
+        let mut now = Instant::now();
+
+        source.show(1, "slack.exe", EMPTY);
+        source.show(2, "slack.exe", EMPTY);
+        process(&mut placer, &mut source, &rules, now);
+        source.windows.remove(&2);
+
+        for _ in 0..3 {
+            now += Duration::from_secs(1);
+            assert!(process(&mut placer, &mut source, &rules, now).is_empty());
+        }
+        assert!(!placer.has_pending());
+    }
+
+    #[test]
+    fn reused_handles_of_destroyed_windows_are_placed_again() {
+        let mut source = FakeSource::default();
+        let mut placer = AutoPlacer::new(RetryPolicy::default());
+        let rules = rules();
+        let now = Instant::now();
+
+        source.show(1, "slack.exe", SIZED);
+        assert_eq!(process(&mut placer, &mut source, &rules, now).len(), 1);
+
+        // The window closes and a new window gets the same handle
+        source.destroy(1);
+        source.show(1, "slack.exe", SIZED);
+        let ready = process(&mut placer, &mut source, &rules, now);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].0.handle, HWND(1));
+    }
+
+    #[test]
+    fn destroyed_windows_are_not_retried() {
+        let mut source = FakeSource::default();
+        let mut placer = AutoPlacer::new(RetryPolicy::default());
+        let rules = rules();
+        let now = Instant::now();
+
+        source.show(1, "slack.exe", EMPTY);
+        process(&mut placer, &mut source, &rules, now);
+        assert!(placer.has_pending());
+
+        source.destroy(1);
+        process(&mut placer, &mut source, &rules, now);
+        assert!(!placer.has_pending());
+    }
+}
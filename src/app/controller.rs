@@ -3,29 +3,56 @@
 //! The controller orchestrates between input, domain, UI, and platform layers.
 //! It maintains stable configuration (grids, monitors) and handles state transitions.
 
-use crate::app::state::{AppState, StateEvent, StateMachine};
-use crate::domain::grid::Grid;
+use crate::app::auto_place::{AutoPlacer, RetryPolicy};
+use crate::app::placement_plan::{PlacementPlan, PlannedMove};
+use crate::app::state::{
+    AppState, SelectedZone, SelectingState, SelectionMode, StateEvent, StateMachine,
+};
+use crate::config::hotkeys::{Hotkey, HotkeyAction};
+use crate::config::sessions::{self, Session, SessionError, SessionWindow};
+use crate::config::settings::{AppConfig, GridConfig};
+use crate::config::storage;
+use crate::config::window_rules::{self, KeyRange, RuleAction};
+use crate::config::zone_memory::{self, RememberedZone, ZoneMemory};
+use crate::domain::animation::SlowWindows;
+use crate::domain::core::Rect;
+use crate::domain::cross_monitor_selection::{self, MonitorCell, SelectionEnd};
+use crate::domain::grid::{Grid, GridError, GridFit};
+use crate::domain::history::{PlacementHistory, WindowSnapshot};
+use crate::domain::keyboard::GridCoords;
+use crate::domain::master_stack::{self, MASTER_RATIO_STEP, MasterStack};
+use crate::domain::monitor_transfer::{self, TransferDirection};
+use crate::domain::placement::{PlacementOptions, PlacementOutcome};
+use crate::domain::rescue;
+use crate::domain::swap;
+use crate::domain::tiling;
 use crate::input::{KeyEvent, KeyboardCaptureError, KeyboardCaptureGuard};
 use crate::platform::monitors::{Monitor, MonitorError, enumerate_monitors};
+use crate::platform::window::{WindowError, WindowInfo};
+use crate::platform::window_events::{WinEventSource, WindowEventSource};
+use crate::ui::theme::Theme;
 use crate::ui::{OverlayError, OverlayManager};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use windows::Win32::Foundation::{HWND, WPARAM};
 use windows::Win32::UI::Input::KeyboardAndMouse::{
-    MOD_ALT, MOD_CONTROL, RegisterHotKey, UnregisterHotKey, VK_F9,
+    HOT_KEY_MODIFIERS, MOD_ALT, MOD_CONTROL, MOD_SHIFT, MOD_WIN, RegisterHotKey, UnregisterHotKey,
 };
 use windows::Win32::UI::WindowsAndMessaging::{
     DispatchMessageW, MSG, PM_REMOVE, PeekMessageW, TranslateMessage, WM_HOTKEY, WM_QUIT,
 };
 
-const MAIN_HOTKEY_ID: i32 = 1;
+/// Custom message posted by the main window when the display configuration changes
+///
+/// WM_DISPLAYCHANGE is sent (not posted) to the main window procedure, which
+/// forwards it as this message so the controller sees it in its event loop.
+pub const WM_TACTILE_DISPLAY_CHANGED: u32 = 0x8001; // WM_APP range
 
 /// Application errors that can occur during controller operations
 #[derive(Debug)]
 pub enum AppError {
     /// Monitor enumeration failed
     MonitorError(MonitorError),
-    /// Failed to create grids for monitors
-    GridCreationFailed(String),
     /// No suitable monitors found for grid positioning
     NoSuitableMonitors,
     /// Global hotkey registration failed
@@ -58,7 +85,6 @@ impl std::fmt::Display for AppError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             AppError::MonitorError(e) => write!(f, "Monitor error: {:?}", e),
-            AppError::GridCreationFailed(msg) => write!(f, "Grid creation failed: {}", msg),
             AppError::NoSuitableMonitors => write!(f, "No suitable monitors for grid positioning"),
             AppError::HotkeyRegistrationFailed(msg) => {
                 write!(f, "Hotkey registration failed: {}", msg)
@@ -81,8 +107,9 @@ pub struct OverlayManagerGuard {
 
 impl OverlayManagerGuard {
     /// Create a new overlay manager and initialize with monitors and grids
-    pub fn new(monitors: &[Monitor], grids: &[Grid]) -> Result<Self, AppError> {
+    pub fn new(monitors: &[Monitor], grids: &[Grid], theme: Theme) -> Result<Self, AppError> {
         let mut manager = OverlayManager::new();
+        manager.set_theme(theme);
 
         // Initialize overlay windows for all monitors with their grids
         manager.initialize(monitors, grids)?;
@@ -129,6 +156,16 @@ impl OverlayManagerGuard {
     pub fn render_grids(&mut self) {
         self.manager.render_all_grids();
     }
+
+    /// Highlight a selection on one monitor
+    pub fn set_selection(&mut self, monitor_index: usize, start: GridCoords, end: GridCoords) {
+        self.manager.set_selection(monitor_index, start, end);
+    }
+
+    /// Show the highlighted selection on a monitor as rejected
+    pub fn reject_selection(&mut self, monitor_index: usize) {
+        self.manager.reject_selection(monitor_index);
+    }
 }
 
 impl Drop for OverlayManagerGuard {
@@ -191,6 +228,9 @@ impl Drop for KeyboardCaptureManager {
     }
 }
 
+/// Usable monitors, their grids (same order) and the applied profile name
+type GridSetup = (Vec<Monitor>, Vec<Grid>, Option<String>);
+
 /// Main application controller
 ///
 /// Coordinates between all components and maintains stable configuration.
@@ -206,44 +246,107 @@ pub struct AppController {
     monitors: Vec<Monitor>,
     /// Grid instances per monitor (stable configuration)
     grids: Vec<Grid>,
+    /// User configuration loaded at startup
+    config: AppConfig,
+    /// Name of the monitor profile currently applied, if any
+    active_profile: Option<String>,
+    /// Undo/redo history of placements, keyed by window handle
+    history: PlacementHistory<isize>,
+    /// Monitors in master/stack mode with their window order, keyed by monitor index
+    master_stacks: HashMap<usize, MasterStack<isize>>,
+    /// Grid forced on all monitors by a `layout` rule for the current target window
+    layout_override: Option<GridConfig>,
+    /// Whether the foreground window was excluded by rules when the overlay
+    /// opened, so there is no target until one is picked with Tab
+    foreground_ignored: bool,
+    /// Last zone applied to each application
+    zone_memory: ZoneMemory,
+    /// Matches newly opened windows against the auto-place rules
+    auto_placer: AutoPlacer,
+    /// Windows that repainted too slowly to be animated
+    slow_windows: SlowWindows<isize>,
+    /// Hook reporting newly shown windows (None without auto-place rules)
+    window_events: Option<WinEventSource>,
     /// Main window handle for message processing
     main_window: HWND,
-    /// Tracks whether the hotkey was registered successfully
-    hotkey_registered: bool,
+    /// Actions of the hotkeys that were registered successfully, by hotkey id
+    registered_hotkeys: HashMap<i32, HotkeyAction>,
 }
 
 impl AppController {
-    fn register_main_hotkey(&mut self) -> Result<(), AppError> {
+    /// Registers the configured global hotkeys
+    ///
+    /// Failing to register the overlay hotkey is fatal; other hotkeys that are
+    /// already taken are skipped and logged one by one.
+    fn register_hotkeys(&mut self) -> Result<(), AppError> {
         if self.main_window.0 == 0 {
             println!("AppController: No main window handle - skipping hotkey registration");
             return Ok(());
         }
 
-        unsafe {
-            let modifiers = MOD_CONTROL | MOD_ALT;
-            RegisterHotKey(
-                self.main_window,
-                MAIN_HOTKEY_ID,
-                modifiers,
-                u32::from(VK_F9.0),
-            )
-            .map_err(|err| AppError::HotkeyRegistrationFailed(format!("{}", err)))?;
+        for (index, (action, hotkey)) in self.config.hotkeys.bindings().into_iter().enumerate() {
+            let id = index as i32 + 1;
+            if hotkey.conflicts_with_altgr() {
+                eprintln!(
+                    "AppController: Warning: {} uses Ctrl+Alt, which also blocks the AltGr character on this key",
+                    hotkey
+                );
+            }
+
+            let result = unsafe {
+                RegisterHotKey(
+                    self.main_window,
+                    id,
+                    Self::hotkey_modifiers(&hotkey),
+                    u32::from(hotkey.key),
+                )
+            };
+            match result {
+                Ok(_) => {
+                    println!(
+                        "AppController: Hotkey registered {} ({})",
+                        hotkey,
+                        action.description()
+                    );
+                    self.registered_hotkeys.insert(id, action);
+                }
+                Err(e) if action == HotkeyAction::ToggleOverlay => {
+                    return Err(AppError::HotkeyRegistrationFailed(format!(
+                        "{}: {}",
+                        hotkey, e
+                    )));
+                }
+                Err(e) => eprintln!(
+                    "AppController: Could not register {} ({}): {}",
+                    hotkey,
+                    action.description(),
+                    e
+                ),
+            }
         }
 
-        self.hotkey_registered = true;
         Ok(())
     }
 
-    fn unregister_main_hotkey(&mut self) {
-        if !self.hotkey_registered || self.main_window.0 == 0 {
-            return;
-        }
+    /// Converts configured modifiers to RegisterHotKey flags
+    fn hotkey_modifiers(hotkey: &Hotkey) -> HOT_KEY_MODIFIERS {
+        [
+            (hotkey.ctrl, MOD_CONTROL),
+            (hotkey.alt, MOD_ALT),
+            (hotkey.shift, MOD_SHIFT),
+            (hotkey.win, MOD_WIN),
+        ]
+        .into_iter()
+        .filter(|&(active, _)| active)
+        .fold(HOT_KEY_MODIFIERS(0), |flags, (_, flag)| flags | flag)
+    }
 
-        unsafe {
-            let _ = UnregisterHotKey(self.main_window, MAIN_HOTKEY_ID);
+    fn unregister_hotkeys(&mut self) {
+        for (id, _) in self.registered_hotkeys.drain() {
+            unsafe {
+                let _ = UnregisterHotKey(self.main_window, id);
+            }
         }
-
-        self.hotkey_registered = false;
     }
 
     /// Creates a new application controller
@@ -254,43 +357,41 @@ impl AppController {
     /// # Returns
     /// AppController instance or AppError if initialization fails
     pub fn new(main_window: HWND) -> Result<Self, AppError> {
+        let config = storage::load_or_default();
+
         // Initialize monitors using Phase 1 infrastructure
         let monitors = enumerate_monitors()?;
         if monitors.is_empty() {
             return Err(AppError::NoSuitableMonitors);
         }
 
-        // Create grids for each monitor using Phase 2 domain logic
-        let mut grids = Vec::new();
-        for (i, monitor) in monitors.iter().enumerate() {
-            match Grid::new(2, 3, monitor.work_area) {
-                // 2 rows, 3 columns (Q W E / A S D)
-                Ok(grid) => {
-                    grids.push(grid);
-                    println!(
-                        "Monitor {}: Created 2x3 grid (2 rows, 3 cols) for {}x{} area",
-                        i, monitor.work_area.w, monitor.work_area.h
-                    );
-                }
-                Err(e) => {
-                    return Err(AppError::GridCreationFailed(format!(
-                        "Monitor {}: {:?}",
-                        i, e
-                    )));
-                }
-            }
-        }
-
-        if grids.is_empty() {
-            return Err(AppError::NoSuitableMonitors);
-        }
+        let (monitors, grids, active_profile) = Self::build_grids(&config, monitors, None)?;
 
         // Initialize RAII-wrapped components
-        let overlay_manager = OverlayManagerGuard::new(&monitors, &grids)?;
+        let overlay_manager =
+            OverlayManagerGuard::new(&monitors, &grids, Self::overlay_theme(&config))?;
         let keyboard_capture = KeyboardCaptureManager::new(main_window);
 
+        // Only watch for new windows when there is something to place
+        let window_events = if config.auto_place.is_empty() {
+            None
+        } else {
+            let source = WinEventSource::install();
+            match source {
+                Some(_) => println!(
+                    "AppController: Watching for new windows ({} auto-place rules)",
+                    config.auto_place.len()
+                ),
+                None => eprintln!("AppController: Failed to install window event hook"),
+            }
+            source
+        };
+
         // Start in idle mode - hotkey activates selection
-        println!("AppController: Starting in IDLE mode - press Ctrl+Alt+F9 to activate");
+        println!(
+            "AppController: Starting in IDLE mode - press {} to activate",
+            config.hotkeys.toggle_overlay
+        );
         let initial_state = AppState::Idle;
         let state = Arc::new(Mutex::new(initial_state));
 
@@ -300,16 +401,259 @@ impl AppController {
             keyboard_capture,
             monitors,
             grids,
+            config,
+            active_profile,
+            history: PlacementHistory::new(),
+            master_stacks: HashMap::new(),
+            layout_override: None,
+            foreground_ignored: false,
+            zone_memory: zone_memory::load_or_default(),
+            auto_placer: AutoPlacer::new(RetryPolicy::default()),
+            slow_windows: SlowWindows::new(),
+            window_events,
             main_window,
-            hotkey_registered: false,
+            registered_hotkeys: HashMap::new(),
         };
 
-        controller.register_main_hotkey()?;
-        println!("AppController: Hotkey registered (Ctrl+Alt+F9)");
+        controller.register_hotkeys()?;
 
         Ok(controller)
     }
 
+    /// Resolves the configured overlay theme, falling back to the default one
+    fn overlay_theme(config: &AppConfig) -> Theme {
+        config.overlay_theme().unwrap_or_else(|| {
+            eprintln!(
+                "AppController: Unknown theme '{}', using the default theme",
+                config.theme
+            );
+            Theme::default()
+        })
+    }
+
+    /// Creates grids for each monitor according to the best-matching profile
+    ///
+    /// Monitors that cannot host their configured grid fall back to smaller
+    /// layouts down to 1x1. Monitors that cannot host any grid, or are shorter
+    /// than `Grid::MIN_MONITOR_HEIGHT`, are excluded from navigation.
+    ///
+    /// # Arguments
+    /// * `config` - User configuration
+    /// * `monitors` - Connected monitors
+    /// * `layout_override` - Grid to use on every monitor instead of the configured ones
+    ///
+    /// # Returns
+    /// The usable monitors with their grids (same order) and the name of the
+    /// applied profile, or AppError if no monitor is usable
+    fn build_grids(
+        config: &AppConfig,
+        monitors: Vec<Monitor>,
+        layout_override: Option<GridConfig>,
+    ) -> Result<GridSetup, AppError> {
+        let profile = config.active_profile(&monitors);
+        match profile {
+            Some(p) => println!("AppController: Using monitor profile '{}'", p.name),
+            None => println!("AppController: No monitor profile matched - using default grid"),
+        }
+        let profile_name = profile.map(|p| p.name.clone());
+
+        // Create grids for each monitor using Phase 2 domain logic
+        let mut usable_monitors = Vec::new();
+        let mut grids = Vec::new();
+        for (i, monitor) in monitors.iter().enumerate() {
+            let grid_config =
+                layout_override.unwrap_or_else(|| config.grid_for_monitor(profile, monitor));
+            let area = monitor.work_area;
+            println!(
+                "Monitor {} ({}): {} orientation, requesting {} grid",
+                i,
+                monitor.id,
+                monitor.orientation(),
+                grid_config
+            );
+
+            if monitor.should_reject(Grid::MIN_MONITOR_HEIGHT) {
+                println!(
+                    "Monitor {} ({}): Excluded from navigation - {}x{} area is shorter than {}px",
+                    i,
+                    monitor.id,
+                    area.w,
+                    area.h,
+                    Grid::MIN_MONITOR_HEIGHT
+                );
+                continue;
+            }
+
+            let grid = match Grid::fit(grid_config.rows, grid_config.cols, area) {
+                GridFit::Exact(grid) => {
+                    println!(
+                        "Monitor {} ({}): Created {} grid ({} rows, {} cols) for {}x{} area",
+                        i,
+                        monitor.id,
+                        grid_config,
+                        grid_config.rows,
+                        grid_config.cols,
+                        area.w,
+                        area.h
+                    );
+                    grid
+                }
+                GridFit::Reduced(grid) => {
+                    let (rows, cols) = grid.dimensions();
+                    println!(
+                        "Monitor {} ({}): {} grid does not fit {}x{} area - falling back to {}x{} ({} rows, {} cols)",
+                        i, monitor.id, grid_config, area.w, area.h, cols, rows, rows, cols
+                    );
+                    grid
+                }
+                GridFit::Unusable(e) => {
+                    println!(
+                        "Monitor {} ({}): Excluded from navigation - no grid fits {}x{} area ({:?})",
+                        i, monitor.id, area.w, area.h, e
+                    );
+                    continue;
+                }
+            };
+
+            grids.push(grid);
+            usable_monitors.push(monitor.clone());
+        }
+
+        if grids.is_empty() {
+            return Err(AppError::NoSuitableMonitors);
+        }
+
+        Ok((usable_monitors, grids, profile_name))
+    }
+
+    /// Handles a change in the connected display set
+    ///
+    /// Re-enumerates monitors, re-selects the monitor profile and rebuilds
+    /// grids and overlays. Any selection in progress is cancelled first since
+    /// its monitor indices may no longer be valid.
+    pub fn handle_display_change(&mut self) {
+        println!("AppController: Display configuration changed");
+
+        if matches!(self.get_state(), AppState::Selecting(_)) {
+            self.handle_cancellation();
+        }
+
+        // Monitor indices may now refer to different monitors
+        self.master_stacks.clear();
+        self.rebuild_grids();
+
+        if self.config.rescue.on_display_change {
+            self.gather_windows();
+        }
+    }
+
+    /// Re-enumerates monitors and rebuilds grids and overlays
+    ///
+    /// Keeps the previous configuration if any step fails.
+    ///
+    /// # Returns
+    /// true if the grids were rebuilt
+    fn rebuild_grids(&mut self) -> bool {
+        let monitors = match enumerate_monitors() {
+            Ok(monitors) => monitors,
+            Err(e) => {
+                eprintln!("Failed to enumerate monitors: {}", e);
+                return false;
+            }
+        };
+
+        let (monitors, grids, active_profile) =
+            match Self::build_grids(&self.config, monitors, self.layout_override) {
+                Ok(result) => result,
+                Err(e) => {
+                    eprintln!("Keeping previous grids: {}", e);
+                    return false;
+                }
+            };
+
+        let theme = Self::overlay_theme(&self.config);
+        let overlay_manager = match OverlayManagerGuard::new(&monitors, &grids, theme) {
+            Ok(manager) => manager,
+            Err(e) => {
+                eprintln!("Failed to rebuild overlays: {}", e);
+                return false;
+            }
+        };
+
+        if active_profile != self.active_profile {
+            println!(
+                "AppController: Monitor profile switched from {:?} to {:?}",
+                self.active_profile, active_profile
+            );
+        }
+
+        self.overlay_manager = overlay_manager;
+        self.monitors = monitors;
+        self.grids = grids;
+        self.active_profile = active_profile;
+        true
+    }
+
+    /// Resolves the rule action for a window
+    ///
+    /// Shell windows and full-screen windows are always ignored; otherwise
+    /// the first matching configured rule applies.
+    fn window_action(&self, window_info: &WindowInfo) -> Option<RuleAction> {
+        let monitor_rects: Vec<_> = self.monitors.iter().map(|m| m.physical_rect).collect();
+        let visible_rect =
+            crate::platform::window::get_frame_margins(window_info.handle).shrink(window_info.rect);
+        window_rules::action_for(
+            &self.config.rules,
+            window_info,
+            visible_rect,
+            &monitor_rects,
+        )
+    }
+
+    /// Applies the rules of the foreground window before opening the overlay
+    ///
+    /// Switches the grids to a `layout` rule's grid, or back to the configured
+    /// grids when the previous target had a layout rule. An ignored foreground
+    /// window (e.g. the desktop) is not a target; the overlay still opens so a
+    /// window can be picked with Tab.
+    fn prepare_for_window(&mut self) {
+        // Selection still works without a foreground window; placement reports the error later
+        let action = crate::platform::window::get_active_window()
+            .ok()
+            .and_then(|window_info| {
+                let action = self.window_action(&window_info);
+                if action == Some(RuleAction::Ignore) {
+                    println!(
+                        "'{}' is excluded by rules - press Tab to pick a window",
+                        window_info.title
+                    );
+                }
+                action
+            });
+
+        self.foreground_ignored = action == Some(RuleAction::Ignore);
+        self.set_layout_override(Self::layout_override_for(action));
+    }
+
+    /// Returns the grid a rule action forces on all monitors, if any
+    fn layout_override_for(action: Option<RuleAction>) -> Option<GridConfig> {
+        match action {
+            Some(RuleAction::Layout { grid }) => Some(grid),
+            _ => None,
+        }
+    }
+
+    /// Rebuilds the grids if the layout override changes
+    fn set_layout_override(&mut self, layout_override: Option<GridConfig>) {
+        if layout_override != self.layout_override {
+            println!("AppController: Layout override {:?}", layout_override);
+            let previous = std::mem::replace(&mut self.layout_override, layout_override);
+            if !self.rebuild_grids() {
+                self.layout_override = previous;
+            }
+        }
+    }
+
     /// Gets the current application state (thread-safe)
     ///
     /// # Returns
@@ -392,8 +736,26 @@ impl AppController {
     /// Coordinates state transitions with overlay display and keyboard capture.
     pub fn handle_hotkey(&mut self) {
         println!("AppController: Hotkey pressed");
+        if matches!(self.get_state(), AppState::Idle) {
+            self.prepare_for_window();
+        }
         let new_state = self.process_event(StateEvent::HotkeyPressed);
+        self.apply_activation(new_state);
+    }
 
+    /// Handles the swap hotkey: picks two zones whose windows trade places
+    pub fn handle_swap_hotkey(&mut self) {
+        println!("AppController: Swap hotkey pressed");
+        if matches!(self.get_state(), AppState::Idle) {
+            // Zones always refer to the configured grids
+            self.set_layout_override(None);
+        }
+        let new_state = self.process_event(StateEvent::SwapHotkeyPressed);
+        self.apply_activation(new_state);
+    }
+
+    /// Shows or hides the overlays after a hotkey toggled the selection
+    fn apply_activation(&mut self, new_state: AppState) {
         match new_state {
             AppState::Idle => {
                 println!("Switched to Idle state");
@@ -441,6 +803,25 @@ impl AppController {
 
                     // Convert key to coordinates
                     if let Ok(coords) = grid.key_to_coords(key) {
+                        // Second corner on another monitor: combine across grids
+                        if selecting.spans_monitors()
+                            && let (Some(start_index), Some(start_coords)) = (
+                                selecting.start_monitor_index,
+                                selecting.selection.get_start_coords(),
+                            )
+                        {
+                            println!("Selection completed across monitors!");
+                            self.apply_cross_monitor_selection(
+                                MonitorCell::new(start_index, start_coords),
+                                MonitorCell::new(selecting.active_monitor_index, coords),
+                            );
+                            return;
+                        }
+
+                        if selecting.selection.is_empty() {
+                            selecting.start_monitor_index = Some(selecting.active_monitor_index);
+                        }
+
                         // Update selection
                         match selecting.selection.add_coords(coords) {
                             Ok(_) => {
@@ -454,8 +835,12 @@ impl AppController {
                                     // Apply selection and return to idle
                                     self.apply_selection();
                                 } else {
-                                    // Update overlay rendering to show selection progress
-                                    self.overlay_manager.render_grids();
+                                    // Highlight the first corner until the second is picked
+                                    self.overlay_manager.set_selection(
+                                        selecting.active_monitor_index,
+                                        coords,
+                                        coords,
+                                    );
                                 }
                             }
                             Err(e) => {
@@ -523,11 +908,31 @@ impl AppController {
         }
     }
 
+    /// Resolves a single-monitor selection to its screen rectangle
+    ///
+    /// # Returns
+    /// The selected area and whether it spans every cell of the grid, or
+    /// GridError if the coordinates are outside the grid
+    fn selection_target(
+        grid: &Grid,
+        top_left: GridCoords,
+        bottom_right: GridCoords,
+    ) -> Result<(Rect, bool), GridError> {
+        let target_rect = grid.coords_to_rect(top_left, bottom_right)?;
+        Ok((target_rect, grid.spans_all_cells(top_left, bottom_right)))
+    }
+
     /// Applies completed selection to active window
     pub fn apply_selection(&mut self) {
-        println!("AppController: Applying selection to active window");
-
         let current_state = self.get_state();
+        if let AppState::Selecting(selecting) = &current_state
+            && matches!(selecting.mode, SelectionMode::Swap { .. })
+        {
+            self.apply_swap_zone(selecting.clone());
+            return;
+        }
+
+        println!("AppController: Applying selection to active window");
         if let AppState::Selecting(selecting) = current_state {
             println!("DEBUG: Active monitor: {}", selecting.active_monitor_index);
             println!("DEBUG: Selection state: {:?}", selecting.selection.state());
@@ -542,8 +947,8 @@ impl AppController {
                 // Get the grid for the active monitor
                 if let Some(grid) = self.get_grid(selecting.active_monitor_index) {
                     // Convert selection to screen rectangle
-                    match grid.coords_to_rect(top_left, bottom_right) {
-                        Ok(target_rect) => {
+                    match Self::selection_target(grid, top_left, bottom_right) {
+                        Ok((target_rect, covers_whole_grid)) => {
                             println!(
                                 "Selection: ({},{}) to ({},{}) = screen rect ({},{}) {}x{}",
                                 top_left.row,
@@ -556,27 +961,17 @@ impl AppController {
                                 target_rect.h
                             );
 
-                            // Get the active window and position it
-                            match crate::platform::window::get_active_window() {
-                                Ok(window_info) => {
-                                    println!("Active window: {}", window_info.title);
-
-                                    // Position the window
-                                    match crate::platform::window::position_window(
-                                        window_info.handle,
-                                        target_rect,
-                                    ) {
-                                        Ok(_) => {
-                                            println!("✓ Window positioned successfully");
-                                        }
-                                        Err(e) => {
-                                            eprintln!("Failed to position window: {}", e);
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("Failed to get active window: {}", e);
-                                }
+                            let zone = self.zone_for_selection(
+                                selecting.active_monitor_index,
+                                grid,
+                                top_left,
+                                bottom_right,
+                            );
+                            if let Some(window_info) =
+                                self.position_target_window(target_rect, covers_whole_grid)
+                                && let Some(zone) = zone
+                            {
+                                self.remember_zone(&window_info, zone);
                             }
                         }
                         Err(e) => {
@@ -605,6 +1000,1034 @@ impl AppController {
         }
     }
 
+    /// Records a completed zone of a swap selection
+    ///
+    /// After the first zone the selection restarts for the second one; once
+    /// both are picked their windows trade places and the selection ends.
+    fn apply_swap_zone(&mut self, mut selecting: SelectingState) {
+        let Some((start, end)) = selecting.selection.get_normalized_coords() else {
+            eprintln!("Swap zone is incomplete - cancelling");
+            self.handle_cancellation();
+            return;
+        };
+        let zone = SelectedZone {
+            monitor_index: selecting.active_monitor_index,
+            start,
+            end,
+        };
+
+        let Some((first, second)) = selecting.complete_swap_zone(zone) else {
+            println!("First zone selected - pick the zone to swap with");
+            *self.state.lock().unwrap() = AppState::Selecting(selecting);
+            self.overlay_manager
+                .set_selection(zone.monitor_index, zone.start, zone.end);
+            return;
+        };
+
+        self.swap_zones(first, second);
+
+        let new_state = self.process_event(StateEvent::SelectionCompleted);
+        if let AppState::Idle = new_state {
+            println!("Swap completed, returned to idle");
+            self.overlay_manager.hide_all();
+            self.keyboard_capture.stop_capture();
+        }
+    }
+
+    /// Exchanges the windows occupying two zones in one batch
+    ///
+    /// Occupants are the windows overlapping each zone the most. If one zone
+    /// is empty, the other zone's window moves into it.
+    fn swap_zones(&mut self, first: SelectedZone, second: SelectedZone) {
+        let zone_rect = |zone: SelectedZone| {
+            self.get_grid(zone.monitor_index)
+                .and_then(|grid| grid.coords_to_rect(zone.start, zone.end).ok())
+        };
+        let (Some(first_rect), Some(second_rect)) = (zone_rect(first), zone_rect(second)) else {
+            eprintln!("Failed to resolve swap zones");
+            return;
+        };
+
+        let windows: Vec<WindowInfo> = crate::platform::window::enumerate_candidate_windows()
+            .into_iter()
+            .filter(|w| self.window_action(w) != Some(RuleAction::Ignore))
+            .collect();
+        let visible_rects: Vec<Rect> = windows
+            .iter()
+            .map(|w| crate::platform::window::get_frame_margins(w.handle).shrink(w.rect))
+            .collect();
+
+        let moves = match swap::swap_moves(first_rect, second_rect, &visible_rects) {
+            Ok(moves) => moves,
+            Err(e) => {
+                println!("Nothing to swap: {:?}", e);
+                return;
+            }
+        };
+
+        let assignments: Vec<(&WindowInfo, Rect)> = moves
+            .into_iter()
+            .map(|(index, target)| (&windows[index], target))
+            .collect();
+        self.place_batch(&assignments);
+    }
+
+    /// Applies a selection whose corners were picked on two different monitors
+    ///
+    /// The combined rectangle is resolved by the configured cross-monitor policy;
+    /// a refused selection leaves the window untouched.
+    ///
+    /// # Arguments
+    /// * `start` - First corner and the monitor it was picked on
+    /// * `end` - Second corner and the monitor it was picked on
+    pub fn apply_cross_monitor_selection(&mut self, start: MonitorCell, end: MonitorCell) {
+        if let AppState::Selecting(selecting) = self.get_state()
+            && matches!(selecting.mode, SelectionMode::Swap { .. })
+        {
+            eprintln!("Swap zones must be picked on a single monitor - cancelling");
+            self.handle_cancellation();
+            return;
+        }
+
+        println!(
+            "AppController: Applying selection from monitor {} to monitor {}",
+            start.monitor_index, end.monitor_index
+        );
+
+        match (self.selection_end(start), self.selection_end(end)) {
+            (Some(start_end), Some(end_end)) => {
+                match cross_monitor_selection::combine_selection(
+                    start_end,
+                    end_end,
+                    self.config.cross_monitor_policy,
+                ) {
+                    Ok(target_rect) => {
+                        println!(
+                            "Cross-monitor selection ({:?}) = screen rect ({},{}) {}x{}",
+                            self.config.cross_monitor_policy,
+                            target_rect.x,
+                            target_rect.y,
+                            target_rect.w,
+                            target_rect.h
+                        );
+                        // A window cannot be maximized across two monitors, so
+                        // cross-monitor selections are never full-grid selections
+                        self.position_target_window(target_rect, false);
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "Cross-monitor selection refused: {:?} - pick another area",
+                            e
+                        );
+                        self.reject_selection(start.monitor_index);
+                        return;
+                    }
+                }
+            }
+            _ => {
+                eprintln!("Failed to resolve cross-monitor selection cells");
+            }
+        }
+
+        // Transition back to idle
+        let new_state = self.process_event(StateEvent::SelectionCompleted);
+        if let AppState::Idle = new_state {
+            println!("Selection completed, returned to idle");
+            self.overlay_manager.hide_all();
+            self.keyboard_capture.stop_capture();
+        }
+    }
+
+    /// Shows the first corner as rejected and lets the user pick again
+    ///
+    /// The overlays stay open; the next key starts a new selection.
+    fn reject_selection(&mut self, monitor_index: usize) {
+        if let AppState::Selecting(mut selecting) = self.get_state() {
+            selecting.restart_selection();
+            *self.state.lock().unwrap() = AppState::Selecting(selecting);
+        }
+        self.overlay_manager.reject_selection(monitor_index);
+    }
+
+    /// Resolves a picked cell into its screen geometry
+    ///
+    /// # Returns
+    /// Cell rectangle with its monitor's work area and DPI, or None if invalid
+    fn selection_end(&self, cell: MonitorCell) -> Option<SelectionEnd> {
+        let monitor = self.get_monitor(cell.monitor_index)?;
+        let grid = self.get_grid(cell.monitor_index)?;
+        let cell_rect = grid.cell_rect(cell.coords).ok()?;
+
+        Some(SelectionEnd {
+            cell_rect,
+            work_area: grid.screen_area(),
+            dpi_scale: monitor.dpi_scale,
+        })
+    }
+
+    /// Moves the foreground window to the adjacent monitor, keeping its relative placement
+    ///
+    /// Grid-aligned windows are snapped to the target monitor's grid; other windows
+    /// keep their fractional position and size. Works without opening the overlay.
+    ///
+    /// # Arguments
+    /// * `direction` - Whether to move to the next or previous monitor
+    pub fn move_window_to_adjacent_monitor(&mut self, direction: TransferDirection) {
+        println!("AppController: Moving window to {:?} monitor", direction);
+
+        if self.monitors.len() < 2 {
+            println!("Only one usable monitor - nothing to do");
+            return;
+        }
+
+        let window_info = match crate::platform::window::get_active_window() {
+            Ok(info) => info,
+            Err(e) => {
+                eprintln!("Failed to get active window: {}", e);
+                return;
+            }
+        };
+
+        // Map the visible frame: position_window adds the invisible borders back
+        let margins = crate::platform::window::get_frame_margins(window_info.handle);
+        let visible_rect = margins.shrink(window_info.rect);
+
+        let work_areas: Vec<_> = self.grids.iter().map(|g| g.screen_area()).collect();
+        let Some(source_index) = monitor_transfer::area_containing(visible_rect, &work_areas)
+        else {
+            eprintln!("Window '{}' is not on a usable monitor", window_info.title);
+            return;
+        };
+        let Some(options) = self.placement_options(&window_info) else {
+            println!("Ignoring '{}' (excluded by rules)", window_info.title);
+            return;
+        };
+        let target_index =
+            monitor_transfer::adjacent_monitor(source_index, self.monitors.len(), direction);
+
+        let target_rect = monitor_transfer::transfer_rect(
+            visible_rect,
+            &self.grids[source_index],
+            &self.grids[target_index],
+        );
+        println!(
+            "Moving '{}' from monitor {} to monitor {}: ({},{}) {}x{}",
+            window_info.title,
+            source_index,
+            target_index,
+            target_rect.x,
+            target_rect.y,
+            target_rect.w,
+            target_rect.h
+        );
+
+        // Crossing into a monitor with a different DPI makes per-monitor aware
+        // windows rescale themselves on WM_DPICHANGED. Moving first lets that
+        // happen before the final bounds are applied.
+        let source_dpi = self.monitors[source_index].dpi_scale;
+        let target_dpi = self.monitors[target_index].dpi_scale;
+        let options = &options;
+        if (source_dpi - target_dpi).abs() > f32::EPSILON {
+            let _ = self.position_window(&window_info, target_rect, options);
+        }
+
+        match self.position_window(&window_info, target_rect, options) {
+            Ok(outcome) => {
+                println!("✓ Window moved: {}", outcome);
+                self.record_placement(&window_info);
+            }
+            Err(e) => eprintln!("Failed to move window: {}", e),
+        }
+    }
+
+    /// Records a completed placement in the undo history
+    ///
+    /// # Arguments
+    /// * `before` - Window information captured before the placement
+    fn record_placement(&mut self, before: &WindowInfo) {
+        match crate::platform::window::get_window_info(before.handle) {
+            Ok(after) => self.history.record(
+                before.handle.0,
+                WindowSnapshot::new(before.rect, before.is_maximized, before.normal_rect),
+                WindowSnapshot::new(after.rect, after.is_maximized, after.normal_rect),
+            ),
+            Err(e) => eprintln!("Failed to record placement: {}", e),
+        }
+    }
+
+    /// Returns the foreground window to the geometry before its last placement
+    pub fn undo_placement(&mut self) {
+        println!("AppController: Undo placement");
+        self.step_history(PlacementHistory::undo, "undo");
+    }
+
+    /// Re-applies the last undone placement of the foreground window
+    pub fn redo_placement(&mut self) {
+        println!("AppController: Redo placement");
+        self.step_history(PlacementHistory::redo, "redo");
+    }
+
+    /// Applies one undo or redo step to the foreground window
+    fn step_history(
+        &mut self,
+        step: fn(&mut PlacementHistory<isize>, &isize) -> Option<WindowSnapshot>,
+        action: &str,
+    ) {
+        let window_info = match crate::platform::window::get_active_window() {
+            Ok(info) => info,
+            Err(e) => {
+                eprintln!("Failed to get active window: {}", e);
+                return;
+            }
+        };
+
+        let Some(snapshot) = step(&mut self.history, &window_info.handle.0) else {
+            println!("Nothing to {} for '{}'", action, window_info.title);
+            return;
+        };
+
+        match crate::platform::window::apply_window_geometry(window_info.handle, &snapshot) {
+            Ok(_) => println!("✓ {} applied to '{}'", action, window_info.title),
+            Err(e) => {
+                eprintln!("Failed to {} placement: {}", action, e);
+                // The window is gone or unusable; its history no longer applies
+                self.history.forget(&window_info.handle.0);
+            }
+        }
+    }
+
+    /// Returns the placement options for a window after applying its rule
+    ///
+    /// # Returns
+    /// The options to place the window with, or None if the window is ignored
+    fn placement_options(&self, window_info: &WindowInfo) -> Option<PlacementOptions> {
+        let mut options = self.config.placement;
+        match self.window_action(window_info) {
+            Some(RuleAction::Ignore) => return None,
+            Some(RuleAction::MoveOnly) => options.move_only = true,
+            _ => {}
+        }
+        options.animation = self
+            .slow_windows
+            .options_for(&window_info.handle.0, options.animation);
+        Some(options)
+    }
+
+    /// Moves a window to a target area, see `platform::window::position_window`
+    ///
+    /// Windows that repaint too slowly to follow the animation are
+    /// remembered and placed without animation afterwards.
+    fn position_window(
+        &mut self,
+        window_info: &WindowInfo,
+        target_rect: Rect,
+        options: &PlacementOptions,
+    ) -> Result<PlacementOutcome, WindowError> {
+        let (outcome, too_slow) = crate::platform::window::position_window_animated(
+            window_info.handle,
+            target_rect,
+            options,
+        )?;
+        self.note_animation_speed(window_info, too_slow);
+        Ok(outcome)
+    }
+
+    /// Remembers a window whose animation was cut short for being too slow
+    fn note_animation_speed(&mut self, window_info: &WindowInfo, too_slow: bool) {
+        if too_slow {
+            println!(
+                "AppController: '{}' repaints too slowly - no longer animating it",
+                window_info.title
+            );
+            self.slow_windows.mark(window_info.handle.0);
+        }
+    }
+
+    /// Shrinks a target area by the gap of the window's `gap` rule, if any
+    fn rule_target(&self, window_info: &WindowInfo, target_rect: Rect) -> Rect {
+        match self.window_action(window_info) {
+            Some(RuleAction::Gap { pixels }) => target_rect.inset(pixels),
+            _ => target_rect,
+        }
+    }
+
+    /// Returns the window the current selection applies to
+    ///
+    /// This is the window picked with Tab during selection, or the
+    /// foreground window if none was picked.
+    fn target_window(&self) -> Result<WindowInfo, WindowError> {
+        match self.get_state() {
+            AppState::Selecting(SelectingState {
+                target_window: Some(handle),
+                ..
+            }) => crate::platform::window::get_window_info(HWND(handle)),
+            // The foreground window is excluded and nothing was picked yet
+            _ if self.foreground_ignored => Err(WindowError::NoActiveWindow),
+            _ => crate::platform::window::get_active_window(),
+        }
+    }
+
+    /// Positions the target window of the selection at the target rectangle
+    ///
+    /// # Arguments
+    /// * `target_rect` - Selected area in screen coordinates
+    /// * `covers_whole_grid` - Whether the selection spans every grid cell
+    ///
+    /// # Returns
+    /// The window information captured before placement, or None if no window was placed
+    fn position_target_window(
+        &mut self,
+        target_rect: Rect,
+        covers_whole_grid: bool,
+    ) -> Option<WindowInfo> {
+        match self.target_window() {
+            Ok(window_info) => {
+                println!("Target window: {}", window_info.title);
+
+                let Some(options) = self.placement_options(&window_info) else {
+                    println!("Ignoring '{}' (excluded by rules)", window_info.title);
+                    return None;
+                };
+                let target_rect = self.rule_target(&window_info, target_rect);
+
+                // Position the window
+                match crate::platform::window::place_window(
+                    window_info.handle,
+                    target_rect,
+                    covers_whole_grid,
+                    &options,
+                ) {
+                    Ok((PlacementOutcome::Exact { .. }, too_slow)) => {
+                        println!("✓ Window positioned successfully");
+                        self.note_animation_speed(&window_info, too_slow);
+                        self.record_placement(&window_info);
+                        Some(window_info)
+                    }
+                    Ok((outcome, too_slow)) => {
+                        println!("✓ Window {}", outcome);
+                        self.note_animation_speed(&window_info, too_slow);
+                        if outcome.overflows() {
+                            println!("  Window is larger than the selected area");
+                        }
+                        self.record_placement(&window_info);
+                        Some(window_info)
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to position window: {}", e);
+                        None
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to get target window: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Describes a single-monitor grid selection as a zone to remember
+    ///
+    /// # Returns
+    /// The zone, or None if the selection cannot be expressed as grid keys
+    fn zone_for_selection(
+        &self,
+        monitor_index: usize,
+        grid: &Grid,
+        top_left: GridCoords,
+        bottom_right: GridCoords,
+    ) -> Option<RememberedZone> {
+        let monitor = self.monitors.get(monitor_index)?;
+        let (rows, cols) = grid.dimensions();
+        Some(RememberedZone {
+            monitor: monitor.id.clone(),
+            layout: GridConfig::new(cols, rows),
+            keys: KeyRange::new(
+                grid.key_for_coords(top_left).ok()?,
+                grid.key_for_coords(bottom_right).ok()?,
+            ),
+        })
+    }
+
+    /// Records the zone applied to a window's application and persists the memory
+    fn remember_zone(&mut self, window_info: &WindowInfo, zone: RememberedZone) {
+        if !self.zone_memory.remember(window_info, zone) {
+            return;
+        }
+
+        let result = zone_memory::zone_memory_path()
+            .and_then(|path| zone_memory::save_to(&self.zone_memory, &path));
+        if let Err(e) = result {
+            eprintln!("Failed to save remembered zones: {}", e);
+        }
+    }
+
+    /// Re-applies the zone last used for the foreground window's application
+    ///
+    /// The zone is placed on the monitor it was selected on, or on the
+    /// primary monitor if that monitor is not connected.
+    pub fn restore_remembered_zone(&mut self) {
+        println!("AppController: Restoring remembered placement");
+
+        let window_info = match crate::platform::window::get_active_window() {
+            Ok(info) => info,
+            Err(e) => {
+                eprintln!("Failed to get active window: {}", e);
+                return;
+            }
+        };
+        let Some(zone) = self.zone_memory.lookup(&window_info).cloned() else {
+            println!("No remembered placement for '{}'", window_info.title);
+            return;
+        };
+        let Some(options) = self.placement_options(&window_info) else {
+            println!("Ignoring '{}' (excluded by rules)", window_info.title);
+            return;
+        };
+
+        let monitor = self
+            .monitors
+            .iter()
+            .find(|m| m.id == zone.monitor)
+            .or_else(|| self.monitors.iter().find(|m| m.is_primary))
+            .or_else(|| self.monitors.first());
+        let Some(monitor) = monitor else {
+            return;
+        };
+
+        let target = Grid::new(zone.layout.rows, zone.layout.cols, monitor.work_area)
+            .and_then(|grid| grid.keys_to_rect(zone.keys.start, zone.keys.end));
+        let target = match target {
+            Ok(rect) => self.rule_target(&window_info, rect),
+            Err(e) => {
+                eprintln!(
+                    "Remembered placement cannot be applied on {}: {:?}",
+                    monitor.id, e
+                );
+                return;
+            }
+        };
+
+        match self.position_window(&window_info, target, &options) {
+            Ok(outcome) => {
+                println!(
+                    "✓ Restored placement of '{}': {}",
+                    window_info.title, outcome
+                );
+                self.record_placement(&window_info);
+            }
+            Err(e) => eprintln!("Failed to restore placement: {}", e),
+        }
+    }
+
+    /// Lists the windows on a monitor that can be placed
+    ///
+    /// # Returns
+    /// Visible top-level windows suitable for positioning and not excluded
+    /// by rules, in z-order (topmost first)
+    fn windows_on_monitor(&self, monitor_index: usize) -> Vec<WindowInfo> {
+        let work_areas: Vec<_> = self.grids.iter().map(|g| g.screen_area()).collect();
+        crate::platform::window::enumerate_candidate_windows()
+            .into_iter()
+            .filter(|w| {
+                monitor_transfer::area_containing(w.rect, &work_areas) == Some(monitor_index)
+            })
+            .filter(|w| self.window_action(w) != Some(RuleAction::Ignore))
+            .collect()
+    }
+
+    /// Tiles all windows on the foreground window's monitor into its grid
+    ///
+    /// Windows are assigned grid spans in z-order by `tiling::tile_spans`
+    /// and placed in one batch. Windows beyond the number of cells are left
+    /// where they are.
+    pub fn tile_monitor(&mut self) {
+        println!("AppController: Tiling windows");
+
+        let monitor_index = self.foreground_monitor_index();
+        let Some(grid) = self.get_grid(monitor_index) else {
+            return;
+        };
+
+        let windows = self.windows_on_monitor(monitor_index);
+        let (rows, cols) = grid.dimensions();
+        let spans = tiling::tile_spans(rows, cols, windows.len());
+        println!(
+            "Tiling {} of {} windows on monitor {} ({}x{} grid)",
+            spans.len(),
+            windows.len(),
+            monitor_index,
+            cols,
+            rows
+        );
+
+        let assignments: Vec<_> = windows
+            .iter()
+            .zip(&spans)
+            .filter_map(|(window_info, span)| {
+                let target = grid.coords_to_rect(span.start, span.end).ok()?;
+                Some((window_info, target))
+            })
+            .collect();
+        self.place_batch(&assignments);
+    }
+
+    /// Places several windows in one batch and records them in the history
+    ///
+    /// Window rules apply to each window; ignored windows are skipped.
+    ///
+    /// # Arguments
+    /// * `assignments` - Windows with their target areas
+    fn place_batch(&mut self, assignments: &[(&WindowInfo, Rect)]) {
+        let mut plan = PlacementPlan::new();
+        for &(window_info, target) in assignments {
+            let Some(options) = self.placement_options(window_info) else {
+                continue;
+            };
+            plan.add(PlannedMove {
+                window: window_info.handle.0,
+                target: self.rule_target(window_info, target),
+                options,
+                maximize: false,
+            });
+        }
+
+        let windows: Vec<&WindowInfo> = assignments.iter().map(|&(w, _)| w).collect();
+        self.execute_plan(&plan, &windows);
+    }
+
+    /// Applies a placement plan in one batch and records the placed windows in the history
+    ///
+    /// # Arguments
+    /// * `plan` - Windows to move and their targets
+    /// * `windows` - Information captured before placement for the planned windows
+    fn execute_plan(&mut self, plan: &PlacementPlan, windows: &[&WindowInfo]) {
+        let outcomes = crate::platform::window::place_windows(&plan.to_placements());
+        for (planned, outcome) in plan.moves().iter().zip(outcomes) {
+            let Some(window_info) = windows.iter().find(|w| w.handle.0 == planned.window) else {
+                continue;
+            };
+            match outcome {
+                Ok(outcome) => {
+                    println!("✓ '{}' {}", window_info.title, outcome);
+                    self.record_placement(window_info);
+                }
+                Err(e) => eprintln!("Failed to place '{}': {}", window_info.title, e),
+            }
+        }
+    }
+
+    /// Returns the monitor of the foreground window, or the first monitor
+    fn foreground_monitor_index(&self) -> usize {
+        let work_areas: Vec<_> = self.grids.iter().map(|g| g.screen_area()).collect();
+        crate::platform::window::get_active_window()
+            .ok()
+            .and_then(|w| monitor_transfer::area_containing(w.rect, &work_areas))
+            .unwrap_or(0)
+    }
+
+    /// Turns master/stack mode on or off for the foreground window's monitor
+    pub fn toggle_master_stack(&mut self) {
+        let monitor_index = self.foreground_monitor_index();
+        if self.master_stacks.remove(&monitor_index).is_some() {
+            println!("Master/stack mode off on monitor {}", monitor_index);
+            return;
+        }
+
+        println!("Master/stack mode on for monitor {}", monitor_index);
+        let stack = MasterStack::new(self.config.master_stack.ratio);
+        self.master_stacks.insert(monitor_index, stack);
+        self.apply_master_stack(monitor_index);
+
+        // Windows opened or closed later re-tile the monitor
+        if self.window_events.is_none() {
+            self.window_events = WinEventSource::install();
+            if self.window_events.is_none() {
+                eprintln!("AppController: Failed to install window event hook");
+            }
+        }
+    }
+
+    /// Makes the foreground window the master of its monitor
+    pub fn promote_window(&mut self) {
+        self.update_master_stack(|stack, window| {
+            stack.promote(window);
+        });
+    }
+
+    /// Moves the foreground window one position down the stack
+    pub fn demote_window(&mut self) {
+        self.update_master_stack(|stack, window| {
+            stack.demote(window);
+        });
+    }
+
+    /// Grows (positive delta) or shrinks (negative delta) the master area
+    pub fn resize_master(&mut self, delta: f32) {
+        self.update_master_stack(|stack, _| stack.adjust_ratio(delta));
+    }
+
+    /// Applies a command to the master/stack layout of the foreground window's monitor
+    fn update_master_stack(&mut self, command: impl FnOnce(&mut MasterStack<isize>, &isize)) {
+        let window_info = match crate::platform::window::get_active_window() {
+            Ok(info) => info,
+            Err(e) => {
+                eprintln!("Failed to get active window: {}", e);
+                return;
+            }
+        };
+
+        let monitor_index = self.foreground_monitor_index();
+        let handles: Vec<isize> = self
+            .windows_on_monitor(monitor_index)
+            .iter()
+            .map(|w| w.handle.0)
+            .collect();
+        let Some(stack) = self.master_stacks.get_mut(&monitor_index) else {
+            println!("Monitor {} is not in master/stack mode", monitor_index);
+            return;
+        };
+
+        stack.sync(&handles);
+        command(stack, &window_info.handle.0);
+        self.apply_master_stack(monitor_index);
+    }
+
+    /// Lays out the windows of a monitor in master/stack mode
+    fn apply_master_stack(&mut self, monitor_index: usize) {
+        let Some(work_area) = self.get_grid(monitor_index).map(|g| g.screen_area()) else {
+            return;
+        };
+        let windows = self.windows_on_monitor(monitor_index);
+        let handles: Vec<isize> = windows.iter().map(|w| w.handle.0).collect();
+        let Some(stack) = self.master_stacks.get_mut(&monitor_index) else {
+            return;
+        };
+        stack.sync(&handles);
+
+        let ordered: Vec<&WindowInfo> = stack
+            .windows()
+            .iter()
+            .filter_map(|handle| windows.iter().find(|w| w.handle.0 == *handle))
+            .collect();
+        let rects = master_stack::master_stack_layout(
+            work_area,
+            ordered.len(),
+            stack.ratio(),
+            self.config.master_stack.gaps,
+        );
+        println!(
+            "Master/stack layout on monitor {}: {} windows, ratio {:.2}",
+            monitor_index,
+            ordered.len(),
+            stack.ratio()
+        );
+
+        let assignments: Vec<_> = ordered.into_iter().zip(rects).collect();
+        self.place_batch(&assignments);
+    }
+
+    /// Re-tiles master/stack monitors whose windows were opened or closed
+    ///
+    /// # Arguments
+    /// * `shown` - Handles of windows shown since the last check
+    /// * `closed` - Handles of windows hidden or destroyed since the last check
+    fn retile_master_stacks(&mut self, shown: &[isize], closed: &[isize]) {
+        if self.master_stacks.is_empty() || (shown.is_empty() && closed.is_empty()) {
+            return;
+        }
+
+        let work_areas: Vec<Rect> = self.grids.iter().map(|g| g.screen_area()).collect();
+        let opened = shown
+            .iter()
+            .filter(|&&handle| {
+                crate::platform::window::is_window_suitable_for_positioning(HWND(handle))
+            })
+            .filter_map(|&handle| crate::platform::window::get_window_info(HWND(handle)).ok())
+            .filter_map(|w| monitor_transfer::area_containing(w.rect, &work_areas));
+        let mut monitors: Vec<usize> = self
+            .master_stacks
+            .iter()
+            .filter(|(_, stack)| closed.iter().any(|handle| stack.contains(handle)))
+            .map(|(&monitor_index, _)| monitor_index)
+            .chain(opened)
+            .filter(|monitor_index| self.master_stacks.contains_key(monitor_index))
+            .collect();
+        monitors.sort_unstable();
+        monitors.dedup();
+
+        for monitor_index in monitors {
+            self.apply_master_stack(monitor_index);
+        }
+    }
+
+    /// Moves windows that are (almost) off-screen onto the nearest monitor
+    ///
+    /// A window is rescued when less than the configured fraction of it lies
+    /// on any monitor's work area. It keeps its size where possible.
+    pub fn gather_windows(&mut self) {
+        println!("AppController: Gathering off-screen windows");
+
+        // All monitors count as visible, including those excluded from grids
+        let work_areas: Vec<Rect> = match enumerate_monitors() {
+            Ok(monitors) => monitors.iter().map(|m| m.work_area).collect(),
+            Err(e) => {
+                eprintln!("Failed to enumerate monitors: {}", e);
+                return;
+            }
+        };
+        let min_visible = self.config.rescue.min_visible_fraction;
+
+        let mut rescued = 0;
+        for window_info in crate::platform::window::enumerate_candidate_windows() {
+            let margins = crate::platform::window::get_frame_margins(window_info.handle);
+            let visible_rect = margins.shrink(window_info.rect);
+            if window_info.is_maximized
+                || !rescue::needs_rescue(visible_rect, &work_areas, min_visible)
+            {
+                continue;
+            }
+            let Some(options) = self.placement_options(&window_info) else {
+                continue;
+            };
+            let Some(area_index) = rescue::nearest_area(visible_rect, &work_areas) else {
+                continue;
+            };
+
+            let target = rescue::rescue_rect(visible_rect, work_areas[area_index]);
+            match self.position_window(&window_info, target, &options) {
+                Ok(outcome) => {
+                    println!("✓ Rescued '{}': {}", window_info.title, outcome);
+                    self.record_placement(&window_info);
+                    rescued += 1;
+                }
+                Err(e) => eprintln!("Failed to rescue '{}': {}", window_info.title, e),
+            }
+        }
+
+        println!("Gathered {} off-screen windows", rescued);
+    }
+
+    /// Handles windows reported by the window event hook
+    ///
+    /// New windows are auto-placed first, so master/stack monitors are
+    /// re-tiled with the windows where they ended up.
+    fn handle_window_events(&mut self) {
+        let Some(source) = self.window_events.as_mut() else {
+            return;
+        };
+        let shown = source.poll_shown();
+        let closed = source.poll_closed();
+        // Only destroyed windows free their handle; hidden ones keep being placed
+        self.auto_placer.forget(&source.poll_destroyed());
+        let ready = self.auto_placer.process(
+            &shown,
+            source,
+            &self.config.auto_place,
+            std::time::Instant::now(),
+        );
+
+        self.place_new_windows(ready);
+        self.retile_master_stacks(&shown, &closed);
+    }
+
+    /// Places newly opened windows that match an auto-place rule
+    ///
+    /// Windows go to the rule's monitor, or the primary monitor if that
+    /// monitor is not connected. Rules excluding a window still apply.
+    ///
+    /// # Arguments
+    /// * `ready` - Windows to place, each with the index of its matching rule
+    fn place_new_windows(&mut self, ready: Vec<(WindowInfo, usize)>) {
+        for (window_info, rule_index) in ready {
+            if !crate::platform::window::is_window_suitable_for_positioning(window_info.handle) {
+                continue;
+            }
+            let Some(options) = self.placement_options(&window_info) else {
+                continue;
+            };
+
+            let rule = &self.config.auto_place[rule_index];
+            let monitor = rule
+                .monitor
+                .as_ref()
+                .and_then(|id| self.monitors.iter().find(|m| &m.id == id))
+                .or_else(|| self.monitors.iter().find(|m| m.is_primary))
+                .or_else(|| self.monitors.first());
+            let Some(monitor) = monitor else {
+                continue;
+            };
+
+            let target = Grid::new(rule.layout.rows, rule.layout.cols, monitor.work_area)
+                .and_then(|grid| grid.keys_to_rect(rule.keys.start, rule.keys.end));
+            let target = match target {
+                Ok(rect) => self.rule_target(&window_info, rect),
+                Err(e) => {
+                    eprintln!(
+                        "Auto-place rule {} cannot be applied on {}: {:?}",
+                        rule_index, monitor.id, e
+                    );
+                    continue;
+                }
+            };
+
+            match self.position_window(&window_info, target, &options) {
+                Ok(outcome) => {
+                    println!("✓ Auto-placed '{}': {}", window_info.title, outcome);
+                    self.record_placement(&window_info);
+                }
+                Err(e) => eprintln!("Failed to auto-place '{}': {}", window_info.title, e),
+            }
+        }
+    }
+
+    /// Saves the arrangement of all placeable windows as a named session
+    pub fn save_session(&mut self, name: &str) {
+        println!("AppController: Saving session '{}'", name);
+
+        // All monitors count, including those too small for a grid
+        let monitors = match enumerate_monitors() {
+            Ok(monitors) => monitors,
+            Err(e) => {
+                eprintln!("Failed to enumerate monitors: {}", e);
+                return;
+            }
+        };
+
+        let windows = crate::platform::window::enumerate_candidate_windows()
+            .into_iter()
+            .filter(|w| self.window_action(w) != Some(RuleAction::Ignore))
+            .filter_map(|w| {
+                let margins = crate::platform::window::get_frame_margins(w.handle);
+                let visible_rect = margins.shrink(w.rect);
+                let monitor = sessions::owning_monitor(visible_rect, &monitors)?;
+                Some(SessionWindow::capture(&w, visible_rect, monitor))
+            })
+            .collect::<Vec<_>>();
+
+        let session = Session {
+            name: name.to_string(),
+            windows,
+        };
+        let result = sessions::sessions_dir()
+            .map_err(SessionError::from)
+            .and_then(|dir| sessions::save_session(&session, &dir));
+        match result {
+            Ok(()) => println!(
+                "✓ Session '{}' saved with {} windows",
+                name,
+                session.windows.len()
+            ),
+            Err(e) => eprintln!("Failed to save session '{}': {}", name, e),
+        }
+    }
+
+    /// Restores a named session onto the matching open windows
+    ///
+    /// Saved windows without a matching open window are skipped.
+    pub fn restore_session(&mut self, name: &str) {
+        println!("AppController: Restoring session '{}'", name);
+
+        let result = sessions::sessions_dir()
+            .map_err(SessionError::from)
+            .and_then(|dir| sessions::load_session(name, &dir));
+        let session = match result {
+            Ok(session) => session,
+            Err(e) => {
+                eprintln!("Failed to load session '{}': {}", name, e);
+                return;
+            }
+        };
+
+        let monitors = match enumerate_monitors() {
+            Ok(monitors) => monitors,
+            Err(e) => {
+                eprintln!("Failed to enumerate monitors: {}", e);
+                return;
+            }
+        };
+
+        let current = crate::platform::window::enumerate_candidate_windows();
+        let pairs = sessions::match_windows(&session.windows, &current);
+        println!(
+            "Matched {} of {} saved windows",
+            pairs.len(),
+            session.windows.len()
+        );
+
+        let mut plan = PlacementPlan::new();
+        let mut windows = Vec::new();
+        for (saved_index, current_index) in pairs {
+            let saved = &session.windows[saved_index];
+            let window_info = &current[current_index];
+            let Some(options) = self.placement_options(window_info) else {
+                continue;
+            };
+
+            plan.add(PlannedMove {
+                window: window_info.handle.0,
+                target: saved.restore_rect(&monitors),
+                options,
+                maximize: saved.maximized,
+            });
+            windows.push(window_info);
+        }
+
+        self.execute_plan(&plan, &windows);
+    }
+
+    /// Picks the next window on the active monitor as the selection target
+    ///
+    /// Candidates are the visible top-level windows suitable for positioning
+    /// that are not excluded by rules, in z-order. The picked window is
+    /// raised (without focus) so it can be seen under the overlay, and its
+    /// `layout` rule, if any, replaces the one of the foreground window.
+    pub fn handle_cycle_target(&mut self) {
+        let AppState::Selecting(mut selecting) = self.get_state() else {
+            println!("Target cycling ignored - not in selecting mode");
+            return;
+        };
+
+        let candidates = self.windows_on_monitor(selecting.active_monitor_index);
+        let handles: Vec<isize> = candidates.iter().map(|w| w.handle.0).collect();
+        let foreground = crate::platform::window::get_active_window()
+            .ok()
+            .map(|w| w.handle.0);
+
+        let Some(target) = selecting.cycle_target(&handles, foreground) else {
+            println!(
+                "No windows to pick on monitor {}",
+                selecting.active_monitor_index
+            );
+            return;
+        };
+
+        let mut layout_override = self.layout_override;
+        if let Some(window_info) = candidates.iter().find(|w| w.handle.0 == target) {
+            println!("Target window: {}", window_info.title);
+            if let Err(e) = crate::platform::window::raise_window(window_info.handle) {
+                eprintln!("Failed to raise target window: {}", e);
+            }
+            layout_override = Self::layout_override_for(self.window_action(window_info));
+        }
+
+        let previous_override = self.layout_override;
+        self.set_layout_override(layout_override);
+        if self.layout_override != previous_override {
+            // The grids and overlays were rebuilt: show the new overlays and
+            // start the selection over, since picked cells refer to the old grid
+            selecting.restart_selection();
+            selecting.active_monitor_index = selecting
+                .active_monitor_index
+                .min(self.monitors.len().saturating_sub(1));
+            self.overlay_manager.show_all();
+            self.overlay_manager
+                .set_active_monitor(selecting.active_monitor_index);
+        }
+
+        *self.state.lock().unwrap() = AppState::Selecting(selecting);
+        self.overlay_manager.render_grids();
+    }
+
     /// Processes keyboard events from the hook callback
     ///
     /// This should be called from the main window procedure when receiving
@@ -636,6 +2059,9 @@ impl AppController {
                     };
                     self.handle_navigation(app_direction);
                 }
+                KeyEvent::CycleTarget => {
+                    self.handle_cycle_target();
+                }
                 KeyEvent::Cancel => {
                     self.handle_cancellation();
                 }
@@ -670,6 +2096,52 @@ impl AppController {
         KeyboardCaptureManager::message_id()
     }
 
+    /// Dispatches a WM_HOTKEY message to the action registered under its id
+    ///
+    /// # Arguments
+    /// * `hotkey_id` - Id passed to RegisterHotKey (the message's wParam)
+    fn handle_hotkey_message(&mut self, hotkey_id: i32) {
+        let Some(&action) = self.registered_hotkeys.get(&hotkey_id) else {
+            println!("AppController: Ignoring unknown hotkey id {}", hotkey_id);
+            return;
+        };
+
+        match action {
+            HotkeyAction::ToggleOverlay => {
+                println!(
+                    "{} pressed! Toggling overlay...",
+                    self.config.hotkeys.toggle_overlay
+                );
+                self.handle_hotkey();
+            }
+            HotkeyAction::MoveToNextMonitor => {
+                self.move_window_to_adjacent_monitor(TransferDirection::Next)
+            }
+            HotkeyAction::MoveToPreviousMonitor => {
+                self.move_window_to_adjacent_monitor(TransferDirection::Previous)
+            }
+            HotkeyAction::Undo => self.undo_placement(),
+            HotkeyAction::Redo => self.redo_placement(),
+            HotkeyAction::Tile => self.tile_monitor(),
+            HotkeyAction::ToggleMasterStack => self.toggle_master_stack(),
+            HotkeyAction::Promote => self.promote_window(),
+            HotkeyAction::Demote => self.demote_window(),
+            HotkeyAction::GrowMaster => self.resize_master(MASTER_RATIO_STEP),
+            HotkeyAction::ShrinkMaster => self.resize_master(-MASTER_RATIO_STEP),
+            HotkeyAction::SaveSession => {
+                let name = self.config.session_name.clone();
+                self.save_session(&name);
+            }
+            HotkeyAction::RestoreSession => {
+                let name = self.config.session_name.clone();
+                self.restore_session(&name);
+            }
+            HotkeyAction::Gather => self.gather_windows(),
+            HotkeyAction::RestoreZone => self.restore_remembered_zone(),
+            HotkeyAction::Swap => self.handle_swap_hotkey(),
+        }
+    }
+
     /// Main event loop for processing keyboard events and timeouts
     pub fn run(&mut self) -> Result<(), AppError> {
         println!("AppController: Starting main event loop");
@@ -691,7 +2163,10 @@ impl AppController {
         }
 
         println!("\n=== APPLICATION READY ===");
-        println!("Press Ctrl+Alt+F9 to activate grid overlay");
+        println!(
+            "Press {} to activate grid overlay",
+            self.config.hotkeys.toggle_overlay
+        );
         println!("========================\n");
         let keyboard_message_id = KeyboardCaptureManager::message_id();
 
@@ -716,13 +2191,14 @@ impl AppController {
                     if msg.message == WM_QUIT {
                         println!("Received WM_QUIT, exiting event loop");
                         break;
-                    } else if msg.message == WM_HOTKEY && msg.wParam.0 == MAIN_HOTKEY_ID as usize {
-                        // Hotkey pressed (Ctrl+Alt+F9) - toggle state
-                        println!("Ctrl+Alt+F9 pressed! Toggling overlay...");
-                        self.handle_hotkey();
+                    } else if msg.message == WM_HOTKEY {
+                        self.handle_hotkey_message(msg.wParam.0 as i32);
                     } else if msg.message == keyboard_message_id {
                         // Handle keyboard event from hook
                         self.handle_keyboard_event(msg.wParam);
+                    } else if msg.message == WM_TACTILE_DISPLAY_CHANGED {
+                        // Monitors were added, removed or reconfigured
+                        self.handle_display_change();
                     } else {
                         // Standard Windows message processing
                         TranslateMessage(&msg);
@@ -730,6 +2206,8 @@ impl AppController {
                     }
                 }
 
+                self.handle_window_events();
+
                 // Small sleep to prevent busy waiting
                 std::thread::sleep(std::time::Duration::from_millis(10));
             }
@@ -743,7 +2221,7 @@ impl AppController {
 impl Drop for AppController {
     fn drop(&mut self) {
         println!("AppController: Shutting down with RAII cleanup");
-        self.unregister_main_hotkey();
+        self.unregister_hotkeys();
         // RAII wrappers will automatically clean up their resources
     }
 }
@@ -751,6 +2229,7 @@ impl Drop for AppController {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::placement::{self, WindowStateAction};
 
     #[test]
     fn controller_creation() {
@@ -789,4 +2268,45 @@ mod tests {
             assert!(matches!(controller.get_state(), AppState::Idle));
         }
     }
+
+    #[test]
+    fn full_grid_selection_maximizes() {
+        let area = Rect::new(0, 0, 1920, 1080);
+        let grid = Grid::new(2, 3, area).unwrap();
+        let options = PlacementOptions::default();
+
+        let (target, covers_whole_grid) =
+            AppController::selection_target(&grid, GridCoords::new(1, 2), GridCoords::new(0, 0))
+                .unwrap();
+        assert_eq!(target, area);
+        assert!(covers_whole_grid);
+        assert_eq!(
+            placement::window_state_action(covers_whole_grid, false, &options),
+            WindowStateAction::Maximize
+        );
+
+        let (target, covers_whole_grid) =
+            AppController::selection_target(&grid, GridCoords::new(0, 0), GridCoords::new(1, 1))
+                .unwrap();
+        assert_eq!(target, Rect::new(0, 0, 1280, 1080));
+        assert!(!covers_whole_grid);
+        assert_eq!(
+            placement::window_state_action(covers_whole_grid, false, &options),
+            WindowStateAction::Place
+        );
+    }
+
+    #[test]
+    fn only_layout_rules_override_the_grid() {
+        let grid = GridConfig::new(4, 2);
+        assert_eq!(
+            AppController::layout_override_for(Some(RuleAction::Layout { grid })),
+            Some(grid)
+        );
+        assert_eq!(
+            AppController::layout_override_for(Some(RuleAction::Ignore)),
+            None
+        );
+        assert_eq!(AppController::layout_override_for(None), None);
+    }
 }
@@ -3,5 +3,8 @@
 //! This module coordinates between input, domain, UI, and platform layers.
 //! It manages the main application state and event handling.
 
+pub mod auto_place;
+#[cfg(windows)]
 pub mod controller;
+pub mod placement_plan;
 pub mod state;
@@ -0,0 +1,153 @@
+//! Multi-window placement plans
+//!
+//! A `PlacementPlan` lists where each window of a multi-window command
+//! (tiling, session restore, swaps) should go before anything is moved.
+//! Plans are plain data so the decisions can be inspected in tests; the
+//! controller executes them with one batched platform call.
+
+use crate::domain::core::Rect;
+use crate::domain::placement::PlacementOptions;
+use crate::platform::window::WindowPlacement;
+use windows::Win32::Foundation::HWND;
+
+/// Planned placement of one window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlannedMove {
+    /// Raw window handle
+    pub window: isize,
+    /// Target area in screen coordinates
+    pub target: Rect,
+    /// Placement options for this window (after rules)
+    pub options: PlacementOptions,
+    /// Maximize the window once placed
+    pub maximize: bool,
+}
+
+/// Ordered set of window placements applied together
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlacementPlan {
+    moves: Vec<PlannedMove>,
+}
+
+impl PlacementPlan {
+    /// Creates an empty plan
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a window to the plan
+    ///
+    /// A window that is already planned keeps its position in the plan but
+    /// takes the new target, so each window is moved once.
+    pub fn add(&mut self, planned: PlannedMove) {
+        match self.moves.iter_mut().find(|m| m.window == planned.window) {
+            Some(existing) => *existing = planned,
+            None => self.moves.push(planned),
+        }
+    }
+
+    /// Returns the planned moves in order
+    pub fn moves(&self) -> &[PlannedMove] {
+        &self.moves
+    }
+
+    /// Returns the target planned for a window
+    #[cfg(test)]
+    pub fn target_of(&self, window: isize) -> Option<Rect> {
+        self.moves
+            .iter()
+            .find(|m| m.window == window)
+            .map(|m| m.target)
+    }
+
+    /// Returns the number of planned windows
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    /// Returns true if the plan moves no window
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// Converts the plan into platform placements, in plan order
+    pub fn to_placements(&self) -> Vec<WindowPlacement> {
+        self.moves
+            .iter()
+            .map(|m| WindowPlacement {
+                hwnd: HWND(m.window),
+                target: m.target,
+                options: m.options,
+                maximize: m.maximize,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn planned(window: isize, target: Rect) -> PlannedMove {
+        PlannedMove {
+            window,
+            target,
+            options: PlacementOptions::default(),
+            maximize: false,
+        }
+    }
+
+    const LEFT: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 960,
+        h: 1040,
+    };
+    const RIGHT: Rect = Rect {
+        x: 960,
+        y: 0,
+        w: 960,
+        h: 1040,
+    };
+
+    #[test]
+    fn moves_keep_insertion_order() {
+        let mut plan = PlacementPlan::new();
+        assert!(plan.is_empty());
+
+        plan.add(planned(7, RIGHT));
+        plan.add(planned(3, LEFT));
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan.moves()[0].window, 7);
+        assert_eq!(plan.target_of(3), Some(LEFT));
+        assert_eq!(plan.target_of(9), None);
+    }
+
+    #[test]
+    fn replanning_a_window_replaces_its_target() {
+        let mut plan = PlacementPlan::new();
+        plan.add(planned(1, LEFT));
+        plan.add(planned(2, RIGHT));
+        plan.add(planned(1, RIGHT));
+
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan.moves()[0], planned(1, RIGHT));
+    }
+
+    #[test]
+    fn placements_follow_plan() {
+        let mut plan = PlacementPlan::new();
+        plan.add(PlannedMove {
+            maximize: true,
+            ..planned(5, LEFT)
+        });
+
+        let placements = plan.to_placements();
+        assert_eq!(placements.len(), 1);
+        assert_eq!(placements[0].hwnd, HWND(5));
+        assert_eq!(placements[0].target, LEFT);
+        assert!(placements[0].maximize);
+    }
+}
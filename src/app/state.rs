@@ -3,6 +3,7 @@
 //! Defines the core application state machine and state transitions.
 //! The state is kept simple with transient selection data only.
 
+use crate::domain::keyboard::GridCoords;
 use crate::domain::selection::Selection;
 use std::time::Instant;
 
@@ -16,6 +17,27 @@ pub enum AppState {
     Selecting(SelectingState),
 }
 
+/// Zone picked on one monitor's grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectedZone {
+    /// Index of the monitor the zone was picked on
+    pub monitor_index: usize,
+    /// Top-left cell of the zone
+    pub start: GridCoords,
+    /// Bottom-right cell of the zone
+    pub end: GridCoords,
+}
+
+/// What a completed selection is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Place the target window in the selected area
+    #[default]
+    Place,
+    /// Swap the windows of two zones; holds the first zone once it is picked
+    Swap { first_zone: Option<SelectedZone> },
+}
+
 /// State during active selection process
 ///
 /// This contains only transient state data. Stable configuration
@@ -26,6 +48,12 @@ pub struct SelectingState {
     pub active_monitor_index: usize,
     /// Current selection progress (start key, completion, etc.)
     pub selection: Selection,
+    /// Monitor on which the first corner was picked (None until a selection starts)
+    pub start_monitor_index: Option<usize>,
+    /// Raw handle of the window picked as the target (None = foreground window)
+    pub target_window: Option<isize>,
+    /// What the selection is used for
+    pub mode: SelectionMode,
     /// Timestamp when selection started (for 30s timeout)
     pub selection_started: Instant,
 }
@@ -39,10 +67,59 @@ impl SelectingState {
         Self {
             active_monitor_index,
             selection: Selection::new(),
+            start_monitor_index: None,
+            target_window: None,
+            mode: SelectionMode::Place,
             selection_started: Instant::now(),
         }
     }
 
+    /// Creates a selecting state that picks two zones to swap
+    ///
+    /// # Arguments
+    /// * `active_monitor_index` - Index of monitor to start selection on
+    pub fn new_swap(active_monitor_index: usize) -> Self {
+        Self {
+            mode: SelectionMode::Swap { first_zone: None },
+            ..Self::new(active_monitor_index)
+        }
+    }
+
+    /// Records a completed zone of a swap selection
+    ///
+    /// The first zone is stored and the selection restarts so the second
+    /// zone can be picked with the same key-pair mechanics.
+    ///
+    /// # Returns
+    /// Both zones once the second one is picked, None otherwise (also
+    /// when the state is not in swap mode)
+    pub fn complete_swap_zone(
+        &mut self,
+        zone: SelectedZone,
+    ) -> Option<(SelectedZone, SelectedZone)> {
+        match self.mode {
+            SelectionMode::Place => None,
+            SelectionMode::Swap {
+                first_zone: Some(first),
+            } => Some((first, zone)),
+            SelectionMode::Swap { first_zone: None } => {
+                self.mode = SelectionMode::Swap {
+                    first_zone: Some(zone),
+                };
+                self.restart_selection();
+                None
+            }
+        }
+    }
+
+    /// Discards the picked corners so the selection starts over
+    ///
+    /// The mode, target window and active monitor are kept.
+    pub fn restart_selection(&mut self) {
+        self.selection.reset();
+        self.start_monitor_index = None;
+    }
+
     /// Checks if the selection has timed out (30 seconds)
     ///
     /// # Returns
@@ -61,12 +138,51 @@ impl SelectingState {
 
     /// Switches to a different monitor during selection
     ///
+    /// A selection in progress is kept so its second corner can be picked on
+    /// the new monitor (cross-monitor selection).
+    ///
     /// # Arguments
     /// * `monitor_index` - Index of monitor to switch to
     pub fn switch_monitor(&mut self, monitor_index: usize) {
         self.active_monitor_index = monitor_index;
-        // Reset selection when switching monitors
-        self.selection.reset();
+    }
+
+    /// Picks the next target window from a list of candidates
+    ///
+    /// Cycling starts after the current target, or after the foreground
+    /// window if none was picked yet, and wraps around. A target that is no
+    /// longer a candidate restarts the cycle at the first candidate.
+    ///
+    /// # Arguments
+    /// * `candidates` - Raw window handles in z-order
+    /// * `foreground` - Raw handle of the foreground window, if any
+    ///
+    /// # Returns
+    /// The new target, or None if there are no candidates
+    pub fn cycle_target(
+        &mut self,
+        candidates: &[isize],
+        foreground: Option<isize>,
+    ) -> Option<isize> {
+        let current = self.target_window.or(foreground);
+        let next = match current.and_then(|c| candidates.iter().position(|&w| w == c)) {
+            Some(index) => candidates.get((index + 1) % candidates.len()),
+            None => candidates.first(),
+        }
+        .copied();
+
+        if next.is_some() {
+            self.target_window = next;
+        }
+        next
+    }
+
+    /// Returns true if the first corner was picked on another monitor
+    pub fn spans_monitors(&self) -> bool {
+        self.selection.is_in_progress()
+            && self
+                .start_monitor_index
+                .is_some_and(|index| index != self.active_monitor_index)
     }
 }
 
@@ -75,6 +191,8 @@ impl SelectingState {
 pub enum StateEvent {
     /// Hotkey was pressed
     HotkeyPressed,
+    /// Swap hotkey was pressed
+    SwapHotkeyPressed,
     /// Valid grid key was pressed
     KeyPressed(char),
     /// Navigation key was pressed (arrow keys)
@@ -127,6 +245,11 @@ impl StateMachine {
                 AppState::Selecting(SelectingState::new(0))
             }
 
+            (AppState::Idle, StateEvent::SwapHotkeyPressed) => {
+                println!("STATE MACHINE: Idle -> Selecting (picking zones to swap)");
+                AppState::Selecting(SelectingState::new_swap(0))
+            }
+
             // From Selecting state
             (AppState::Selecting(selecting), StateEvent::KeyPressed(_key)) => {
                 // Process key press in selection
@@ -176,7 +299,7 @@ impl StateMachine {
                 AppState::Idle
             }
 
-            (AppState::Selecting(_), StateEvent::HotkeyPressed) => {
+            (AppState::Selecting(_), StateEvent::HotkeyPressed | StateEvent::SwapHotkeyPressed) => {
                 // Hotkey pressed during selection = toggle off
                 AppState::Idle
             }
@@ -190,6 +313,7 @@ impl StateMachine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::keyboard::GridCoords;
 
     #[test]
     fn default_state_is_idle() {
@@ -288,14 +412,132 @@ mod tests {
     }
 
     #[test]
-    fn monitor_switching_resets_selection() {
+    fn monitor_switching_keeps_selection() {
         let mut selecting = SelectingState::new(0);
 
-        // Simulate some selection progress (this would normally be set by controller)
-        // For now just verify the monitor index changes and selection gets reset
+        // First corner picked on monitor 0 (normally done by the controller)
+        selecting.selection.start(GridCoords::new(1, 0)).unwrap();
+        selecting.start_monitor_index = Some(0);
+
+        // Switching monitors keeps the first corner for a cross-monitor selection
         selecting.switch_monitor(1);
 
         assert_eq!(selecting.active_monitor_index, 1);
+        assert_eq!(
+            selecting.selection.get_start_coords(),
+            Some(GridCoords::new(1, 0))
+        );
+        assert_eq!(selecting.start_monitor_index, Some(0));
+    }
+
+    #[test]
+    fn cycling_target_starts_after_foreground_and_wraps() {
+        let mut selecting = SelectingState::new(0);
+        let candidates = [10, 20, 30];
+
+        assert_eq!(selecting.cycle_target(&candidates, Some(10)), Some(20));
+        assert_eq!(selecting.cycle_target(&candidates, Some(10)), Some(30));
+        assert_eq!(selecting.cycle_target(&candidates, Some(10)), Some(10));
+        assert_eq!(selecting.target_window, Some(10));
+    }
+
+    #[test]
+    fn cycling_target_handles_missing_windows() {
+        let mut selecting = SelectingState::new(0);
+
+        // Foreground window is not a candidate (e.g. the desktop)
+        assert_eq!(selecting.cycle_target(&[10, 20], Some(99)), Some(10));
+
+        // No candidates keeps the current target
+        assert_eq!(selecting.cycle_target(&[], None), None);
+        assert_eq!(selecting.target_window, Some(10));
+    }
+
+    #[test]
+    fn monitor_switching_keeps_selection_in_progress() {
+        let mut selecting = SelectingState::new(0);
+        selecting.selection.start(GridCoords::new(0, 2)).unwrap();
+        selecting.start_monitor_index = Some(0);
+        assert!(!selecting.spans_monitors());
+
+        selecting.switch_monitor(1);
+
+        assert_eq!(
+            selecting.selection.get_start_coords(),
+            Some(GridCoords::new(0, 2))
+        );
+        assert!(selecting.spans_monitors());
+
+        // Returning to the starting monitor makes it a regular selection again
+        selecting.switch_monitor(0);
+        assert!(!selecting.spans_monitors());
+    }
+
+    #[test]
+    fn restarting_keeps_monitor_and_mode() {
+        let mut selecting = SelectingState::new_swap(0);
+        selecting.selection.start(GridCoords::new(0, 2)).unwrap();
+        selecting.start_monitor_index = Some(0);
+        selecting.switch_monitor(1);
+
+        selecting.restart_selection();
         assert!(selecting.selection.is_empty());
+        assert_eq!(selecting.start_monitor_index, None);
+        assert_eq!(selecting.active_monitor_index, 1);
+        assert_eq!(selecting.mode, SelectionMode::Swap { first_zone: None });
+    }
+
+    #[test]
+    fn swap_hotkey_starts_swap_selection() {
+        let state = StateMachine::process_event(AppState::Idle, StateEvent::SwapHotkeyPressed, 2);
+        let AppState::Selecting(selecting) = state else {
+            panic!("Expected selecting state");
+        };
+        assert_eq!(selecting.mode, SelectionMode::Swap { first_zone: None });
+
+        // Either hotkey toggles the selection off
+        let state = StateMachine::process_event(
+            AppState::Selecting(selecting),
+            StateEvent::HotkeyPressed,
+            2,
+        );
+        assert!(matches!(state, AppState::Idle));
+    }
+
+    #[test]
+    fn swap_selection_collects_two_zones() {
+        let first = SelectedZone {
+            monitor_index: 0,
+            start: GridCoords::new(0, 0),
+            end: GridCoords::new(1, 0),
+        };
+        let second = SelectedZone {
+            monitor_index: 1,
+            start: GridCoords::new(0, 2),
+            end: GridCoords::new(0, 2),
+        };
+
+        let mut selecting = SelectingState::new_swap(0);
+        selecting.selection.start(GridCoords::new(0, 0)).unwrap();
+        selecting.start_monitor_index = Some(0);
+
+        // The first zone restarts the selection for the second one
+        assert_eq!(selecting.complete_swap_zone(first), None);
+        assert!(selecting.selection.is_empty());
+        assert_eq!(selecting.start_monitor_index, None);
+
+        assert_eq!(selecting.complete_swap_zone(second), Some((first, second)));
+    }
+
+    #[test]
+    fn placement_selection_ignores_swap_zones() {
+        let mut selecting = SelectingState::new(0);
+        let zone = SelectedZone {
+            monitor_index: 0,
+            start: GridCoords::new(0, 0),
+            end: GridCoords::new(0, 0),
+        };
+        assert_eq!(selecting.complete_swap_zone(zone), None);
+        assert_eq!(selecting.mode, SelectionMode::Place);
     }
 }
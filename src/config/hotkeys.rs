@@ -0,0 +1,407 @@
+//! Global hotkey bindings
+//!
+//! Every global hotkey can be rebound (or, except the overlay hotkey,
+//! disabled with `null`) in the `hotkeys` section of the config file.
+//! Bindings are written as "Win+Alt+Z" and stored as virtual-key codes, so
+//! this module needs no Win32 types.
+//!
+//! The defaults combine letters with Win+Alt rather than Ctrl+Alt: Ctrl+Alt
+//! is the same as AltGr on many European layouts, and a global Ctrl+Alt+Q
+//! would swallow the '@' typed with AltGr+Q on a German keyboard.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Errors that can occur while parsing hotkeys
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HotkeyError {
+    #[error("Invalid hotkey '{0}' (expected modifiers and a key, e.g. \"Win+Alt+Z\")")]
+    Invalid(String),
+
+    #[error("Hotkey '{0}' needs at least one of Ctrl, Alt or Win")]
+    MissingModifier(String),
+}
+
+/// Virtual-key codes of keys with a name, as written in the config file
+const NAMED_KEYS: &[(&str, u16)] = &[
+    ("Backspace", 0x08),
+    ("Tab", 0x09),
+    ("Enter", 0x0D),
+    ("Space", 0x20),
+    ("PageUp", 0x21),
+    ("PageDown", 0x22),
+    ("End", 0x23),
+    ("Home", 0x24),
+    ("Left", 0x25),
+    ("Up", 0x26),
+    ("Right", 0x27),
+    ("Down", 0x28),
+    ("Insert", 0x2D),
+    ("Delete", 0x2E),
+];
+
+/// Virtual-key code of F1; F2..F24 follow consecutively
+const VK_F1: u16 = 0x70;
+
+/// A key combination that triggers a global action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Hotkey {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub win: bool,
+    /// Windows virtual-key code of the key
+    pub key: u16,
+}
+
+impl Hotkey {
+    /// Creates a hotkey without modifiers for a virtual-key code
+    const fn new(key: u16) -> Self {
+        Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            win: false,
+            key,
+        }
+    }
+
+    const fn ctrl_alt(self) -> Self {
+        Self {
+            ctrl: true,
+            alt: true,
+            ..self
+        }
+    }
+
+    const fn win_alt(self) -> Self {
+        Self {
+            win: true,
+            alt: true,
+            ..self
+        }
+    }
+
+    const fn shift(self) -> Self {
+        Self {
+            shift: true,
+            ..self
+        }
+    }
+
+    /// Whether the hotkey can collide with characters typed with AltGr
+    ///
+    /// Windows reports AltGr as Ctrl+Alt, so Ctrl+Alt with a letter, digit
+    /// or punctuation key takes that character away on layouts that use it.
+    pub fn conflicts_with_altgr(&self) -> bool {
+        let is_function_or_named = (VK_F1..VK_F1 + 24).contains(&self.key)
+            || NAMED_KEYS.iter().any(|&(_, code)| code == self.key);
+        self.ctrl && self.alt && !self.win && !is_function_or_named
+    }
+
+    fn key_name(&self) -> String {
+        match self.key {
+            code @ (0x30..=0x39 | 0x41..=0x5A) => char::from(code as u8).to_string(),
+            code if (VK_F1..VK_F1 + 24).contains(&code) => format!("F{}", code - VK_F1 + 1),
+            code => NAMED_KEYS
+                .iter()
+                .find(|&&(_, named)| named == code)
+                .map_or_else(|| format!("0x{:02X}", code), |&(name, _)| name.to_string()),
+        }
+    }
+}
+
+/// Parses a key name: a letter, a digit, F1-F24 or one of `NAMED_KEYS`
+fn parse_key(name: &str) -> Option<u16> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next())
+        && c.is_ascii_alphanumeric()
+    {
+        return Some(c.to_ascii_uppercase() as u16);
+    }
+
+    if let Some(number) = name.strip_prefix(['F', 'f'])
+        && let Ok(number) = number.parse::<u16>()
+        && (1..=24).contains(&number)
+    {
+        return Some(VK_F1 + number - 1);
+    }
+
+    let name = if name.eq_ignore_ascii_case("Return") {
+        "Enter"
+    } else {
+        name
+    };
+    NAMED_KEYS
+        .iter()
+        .find(|(named, _)| named.eq_ignore_ascii_case(name))
+        .map(|&(_, code)| code)
+}
+
+impl FromStr for Hotkey {
+    type Err = HotkeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || HotkeyError::Invalid(s.to_string());
+        let mut parts: Vec<&str> = s.split('+').map(str::trim).collect();
+        let key = parts.pop().and_then(parse_key).ok_or_else(invalid)?;
+
+        let mut hotkey = Self::new(key);
+        for modifier in parts {
+            let flag = match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => &mut hotkey.ctrl,
+                "alt" => &mut hotkey.alt,
+                "shift" => &mut hotkey.shift,
+                "win" | "super" => &mut hotkey.win,
+                _ => return Err(invalid()),
+            };
+            if *flag {
+                return Err(invalid());
+            }
+            *flag = true;
+        }
+
+        // Shift alone would take a typed character away from every application
+        if !(hotkey.ctrl || hotkey.alt || hotkey.win) {
+            return Err(HotkeyError::MissingModifier(s.to_string()));
+        }
+
+        Ok(hotkey)
+    }
+}
+
+impl TryFrom<String> for Hotkey {
+    type Error = HotkeyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Hotkey> for String {
+    fn from(hotkey: Hotkey) -> Self {
+        hotkey.to_string()
+    }
+}
+
+impl std::fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let modifiers = [
+            (self.win, "Win"),
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+        ];
+        for (_, name) in modifiers.iter().filter(|(active, _)| *active) {
+            write!(f, "{}+", name)?;
+        }
+        write!(f, "{}", self.key_name())
+    }
+}
+
+/// Actions that can be bound to a global hotkey
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HotkeyAction {
+    ToggleOverlay,
+    MoveToNextMonitor,
+    MoveToPreviousMonitor,
+    Undo,
+    Redo,
+    Tile,
+    ToggleMasterStack,
+    Promote,
+    Demote,
+    GrowMaster,
+    ShrinkMaster,
+    SaveSession,
+    RestoreSession,
+    Gather,
+    RestoreZone,
+    Swap,
+}
+
+impl HotkeyAction {
+    /// Human-readable description, used in log messages
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::ToggleOverlay => "toggle the grid overlay",
+            Self::MoveToNextMonitor => "move window to next monitor",
+            Self::MoveToPreviousMonitor => "move window to previous monitor",
+            Self::Undo => "undo window placement",
+            Self::Redo => "redo window placement",
+            Self::Tile => "tile all windows on the monitor",
+            Self::ToggleMasterStack => "toggle master/stack mode on the monitor",
+            Self::Promote => "promote window to master",
+            Self::Demote => "demote window in the stack",
+            Self::GrowMaster => "grow master area",
+            Self::ShrinkMaster => "shrink master area",
+            Self::SaveSession => "save window arrangement",
+            Self::RestoreSession => "restore window arrangement",
+            Self::Gather => "gather off-screen windows",
+            Self::RestoreZone => "restore remembered placement",
+            Self::Swap => "swap the windows of two zones",
+        }
+    }
+}
+
+/// Hotkey bindings; `null` disables an action hotkey
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HotkeyConfig {
+    /// Opens and closes the grid overlay; always registered
+    pub toggle_overlay: Hotkey,
+    pub move_to_next_monitor: Option<Hotkey>,
+    pub move_to_previous_monitor: Option<Hotkey>,
+    pub undo: Option<Hotkey>,
+    pub redo: Option<Hotkey>,
+    pub tile: Option<Hotkey>,
+    pub toggle_master_stack: Option<Hotkey>,
+    pub promote: Option<Hotkey>,
+    pub demote: Option<Hotkey>,
+    pub grow_master: Option<Hotkey>,
+    pub shrink_master: Option<Hotkey>,
+    pub save_session: Option<Hotkey>,
+    pub restore_session: Option<Hotkey>,
+    pub gather: Option<Hotkey>,
+    pub restore_zone: Option<Hotkey>,
+    pub swap: Option<Hotkey>,
+}
+
+impl Default for HotkeyConfig {
+    fn default() -> Self {
+        let letter = |c: u8| Hotkey::new(u16::from(c)).win_alt();
+        Self {
+            toggle_overlay: Hotkey::new(VK_F1 + 8).ctrl_alt(), // Ctrl+Alt+F9
+            move_to_next_monitor: Some(Hotkey::new(VK_F1 + 9).ctrl_alt()),
+            move_to_previous_monitor: Some(Hotkey::new(VK_F1 + 9).ctrl_alt().shift()),
+            undo: Some(letter(b'Z')),
+            redo: Some(letter(b'Y')),
+            tile: Some(letter(b'T')),
+            toggle_master_stack: Some(letter(b'M')),
+            promote: Some(Hotkey::new(0x0D).ctrl_alt()),
+            demote: Some(Hotkey::new(0x0D).ctrl_alt().shift()),
+            grow_master: Some(letter(b'L')),
+            shrink_master: Some(letter(b'H')),
+            save_session: Some(letter(b'S')),
+            // Win+Alt+R and Win+Alt+G belong to the Xbox Game Bar
+            restore_session: Some(letter(b'S').shift()),
+            gather: Some(letter(b'G').shift()),
+            restore_zone: Some(letter(b'P')),
+            swap: Some(letter(b'W')),
+        }
+    }
+}
+
+impl HotkeyConfig {
+    /// Returns every enabled binding, the overlay hotkey first
+    pub fn bindings(&self) -> Vec<(HotkeyAction, Hotkey)> {
+        let optional = [
+            (HotkeyAction::MoveToNextMonitor, self.move_to_next_monitor),
+            (
+                HotkeyAction::MoveToPreviousMonitor,
+                self.move_to_previous_monitor,
+            ),
+            (HotkeyAction::Undo, self.undo),
+            (HotkeyAction::Redo, self.redo),
+            (HotkeyAction::Tile, self.tile),
+            (HotkeyAction::ToggleMasterStack, self.toggle_master_stack),
+            (HotkeyAction::Promote, self.promote),
+            (HotkeyAction::Demote, self.demote),
+            (HotkeyAction::GrowMaster, self.grow_master),
+            (HotkeyAction::ShrinkMaster, self.shrink_master),
+            (HotkeyAction::SaveSession, self.save_session),
+            (HotkeyAction::RestoreSession, self.restore_session),
+            (HotkeyAction::Gather, self.gather),
+            (HotkeyAction::RestoreZone, self.restore_zone),
+            (HotkeyAction::Swap, self.swap),
+        ];
+
+        std::iter::once((HotkeyAction::ToggleOverlay, self.toggle_overlay))
+            .chain(
+                optional
+                    .into_iter()
+                    .filter_map(|(action, hotkey)| Some((action, hotkey?))),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hotkey_parsing() {
+        let hotkey: Hotkey = "win+alt+z".parse().unwrap();
+        assert!(hotkey.win && hotkey.alt && !hotkey.ctrl && !hotkey.shift);
+        assert_eq!(hotkey.key, u16::from(b'Z'));
+
+        assert_eq!("Ctrl + Alt + F9".parse::<Hotkey>().unwrap().key, VK_F1 + 8);
+        assert_eq!("Alt+Return".parse::<Hotkey>().unwrap().key, 0x0D);
+        assert_eq!("Alt+pageup".parse::<Hotkey>().unwrap().key, 0x21);
+
+        assert!("Z".parse::<Hotkey>().is_err());
+        assert!("Ctrl+Ctrl+Z".parse::<Hotkey>().is_err());
+        assert!("Hyper+Z".parse::<Hotkey>().is_err());
+        assert!("Ctrl+F25".parse::<Hotkey>().is_err());
+        assert!("Ctrl+".parse::<Hotkey>().is_err());
+        assert_eq!(
+            "Shift+A".parse::<Hotkey>(),
+            Err(HotkeyError::MissingModifier("Shift+A".to_string()))
+        );
+    }
+
+    #[test]
+    fn hotkey_round_trips_as_string() {
+        for text in ["Win+Alt+Shift+S", "Ctrl+Alt+F10", "Ctrl+Alt+Enter", "Win+7"] {
+            let hotkey: Hotkey = text.parse().unwrap();
+            assert_eq!(hotkey.to_string(), text);
+            let json = serde_json::to_string(&hotkey).unwrap();
+            assert_eq!(serde_json::from_str::<Hotkey>(&json).unwrap(), hotkey);
+        }
+    }
+
+    #[test]
+    fn altgr_conflicts() {
+        let conflicts = |s: &str| s.parse::<Hotkey>().unwrap().conflicts_with_altgr();
+        assert!(conflicts("Ctrl+Alt+Q"));
+        assert!(conflicts("Ctrl+Alt+7"));
+        assert!(!conflicts("Ctrl+Alt+F9"));
+        assert!(!conflicts("Ctrl+Alt+Enter"));
+        assert!(!conflicts("Win+Alt+Q"));
+        assert!(!conflicts("Win+Ctrl+Alt+Q"));
+    }
+
+    #[test]
+    fn defaults_do_not_take_altgr_characters() {
+        let bindings = HotkeyConfig::default().bindings();
+        assert_eq!(bindings.len(), 16);
+        assert_eq!(bindings[0].0, HotkeyAction::ToggleOverlay);
+        assert!(
+            bindings
+                .iter()
+                .all(|(_, hotkey)| !hotkey.conflicts_with_altgr())
+        );
+
+        let mut unique: Vec<_> = bindings.iter().map(|(_, hotkey)| *hotkey).collect();
+        unique.sort_by_key(|hotkey| hotkey.to_string());
+        unique.dedup();
+        assert_eq!(unique.len(), bindings.len());
+    }
+
+    #[test]
+    fn null_disables_a_binding() {
+        let json = r#"{ "undo": null, "tile": "Ctrl+Shift+T" }"#;
+        let config: HotkeyConfig = serde_json::from_str(json).unwrap();
+        let bindings = config.bindings();
+        assert!(
+            !bindings
+                .iter()
+                .any(|(action, _)| *action == HotkeyAction::Undo)
+        );
+        assert!(bindings.contains(&(HotkeyAction::Tile, "Ctrl+Shift+T".parse().unwrap())));
+        assert_eq!(config.redo, HotkeyConfig::default().redo);
+    }
+}
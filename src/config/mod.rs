@@ -0,0 +1,12 @@
+//! Configuration and persistence
+//!
+//! This module defines the user settings schema, loads and saves it from
+//! disk, and selects monitor profiles for the connected display set.
+
+pub mod hotkeys;
+pub mod profiles;
+pub mod sessions;
+pub mod settings;
+pub mod storage;
+pub mod window_rules;
+pub mod zone_memory;
@@ -0,0 +1,312 @@
+//! Monitor profiles selected by the connected display set
+//!
+//! A profile bundles grid settings with the conditions under which it applies,
+//! e.g. "the three office monitors are connected" or "an ultrawide is present".
+//! Matching is a pure function of the monitor list so it can be tested with
+//! synthetic monitors.
+//!
+//! ## Scoring
+//! Every rule of a profile must match. Matching rules contribute a specificity
+//! score and the highest total wins; ties go to the profile listed first.
+//! A profile without rules always matches with score 0 and acts as a fallback.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::settings::GridConfig;
+use crate::platform::monitor_id::MonitorId;
+use crate::platform::monitors::Monitor;
+use crate::platform::orientation::Orientation;
+
+/// Minimum width/height ratio considered ultrawide (21:9 is ~2.33)
+pub const ULTRAWIDE_ASPECT_RATIO: f32 = 2.2;
+
+/// Condition that must hold for a profile to apply
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileRule {
+    /// All listed monitors are connected (others may be too)
+    MonitorsPresent(Vec<MonitorId>),
+    /// The connected monitors are exactly the listed ones
+    MonitorsExactly(Vec<MonitorId>),
+    /// Exactly this many monitors are connected
+    MonitorCount(usize),
+    /// At least one connected monitor is ultrawide
+    AnyUltrawide,
+}
+
+impl ProfileRule {
+    /// Scores this rule against the connected monitors
+    ///
+    /// # Returns
+    /// Some(specificity) if the rule matches, None otherwise
+    pub fn score(&self, monitors: &[Monitor]) -> Option<u32> {
+        match self {
+            ProfileRule::MonitorsPresent(ids) => {
+                let all_present = ids.iter().all(|id| monitors.iter().any(|m| &m.id == id));
+                all_present.then_some(100 * ids.len() as u32)
+            }
+            ProfileRule::MonitorsExactly(ids) => {
+                let same_count = ids.len() == monitors.len();
+                let all_present = ids.iter().all(|id| monitors.iter().any(|m| &m.id == id));
+                (same_count && all_present).then_some(100 * ids.len() as u32 + 50)
+            }
+            ProfileRule::MonitorCount(count) => (monitors.len() == *count).then_some(10),
+            ProfileRule::AnyUltrawide => monitors.iter().any(is_ultrawide).then_some(5),
+        }
+    }
+}
+
+/// Named configuration applied when its rules match the connected monitors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorProfile {
+    /// Display name used in logs
+    pub name: String,
+    /// Conditions that must all hold for this profile to apply
+    #[serde(default)]
+    pub rules: Vec<ProfileRule>,
+    /// Grid for landscape and square monitors without a specific override
+    #[serde(default)]
+    pub grid: Option<GridConfig>,
+    /// Grid for portrait monitors without a specific override
+    #[serde(default)]
+    pub portrait_grid: Option<GridConfig>,
+    /// Per-monitor grid overrides
+    #[serde(default)]
+    pub monitor_grids: HashMap<MonitorId, GridConfig>,
+}
+
+impl MonitorProfile {
+    /// Scores this profile against the connected monitors
+    ///
+    /// # Returns
+    /// Some(total score) if every rule matches, None otherwise
+    pub fn score(&self, monitors: &[Monitor]) -> Option<u32> {
+        self.rules
+            .iter()
+            .try_fold(0, |total, rule| rule.score(monitors).map(|s| total + s))
+    }
+
+    /// Returns the grid this profile assigns to a monitor, if any
+    pub fn grid_for(&self, monitor: &Monitor) -> Option<GridConfig> {
+        let orientation_grid = match monitor.orientation() {
+            Orientation::Portrait => self.portrait_grid,
+            Orientation::Landscape | Orientation::Square => self.grid,
+        };
+
+        self.monitor_grids
+            .get(&monitor.id)
+            .copied()
+            .or(orientation_grid)
+    }
+}
+
+/// Selects the best-matching profile for the connected monitors
+///
+/// # Returns
+/// The highest-scoring matching profile (first one wins on ties), or None
+pub fn select_profile<'a>(
+    profiles: &'a [MonitorProfile],
+    monitors: &[Monitor],
+) -> Option<&'a MonitorProfile> {
+    let mut best: Option<(&MonitorProfile, u32)> = None;
+
+    for profile in profiles {
+        if let Some(score) = profile.score(monitors)
+            && best.is_none_or(|(_, best_score)| score > best_score)
+        {
+            best = Some((profile, score));
+        }
+    }
+
+    best.map(|(profile, _)| profile)
+}
+
+/// Returns true if the monitor has an ultrawide aspect ratio
+pub fn is_ultrawide(monitor: &Monitor) -> bool {
+    let rect = monitor.physical_rect;
+    rect.h > 0 && (rect.w as f32) / (rect.h as f32) >= ULTRAWIDE_ASPECT_RATIO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::core::Rect;
+    use windows::Win32::Graphics::Gdi::HMONITOR;
+
+    fn monitor(id: &str, w: i32, h: i32) -> Monitor {
+        Monitor {
+            handle: HMONITOR(0),
+            index: 0,
+            id: MonitorId::new(id),
+            physical_rect: Rect::new(0, 0, w, h),
+            work_area: Rect::new(0, 0, w, h - 40),
+            dpi_scale: 1.0,
+            dpi_x: 96,
+            dpi_y: 96,
+            is_primary: false,
+        }
+    }
+
+    fn profile(name: &str, rules: Vec<ProfileRule>) -> MonitorProfile {
+        MonitorProfile {
+            name: name.to_string(),
+            rules,
+            grid: None,
+            portrait_grid: None,
+            monitor_grids: HashMap::new(),
+        }
+    }
+
+    fn ids(names: &[&str]) -> Vec<MonitorId> {
+        names.iter().map(|n| MonitorId::new(*n)).collect()
+    }
+
+    #[test]
+    fn present_rule_allows_extra_monitors() {
+        let monitors = vec![monitor("A", 1920, 1080), monitor("B", 1920, 1080)];
+
+        assert_eq!(
+            ProfileRule::MonitorsPresent(ids(&["A"])).score(&monitors),
+            Some(100)
+        );
+        assert_eq!(
+            ProfileRule::MonitorsPresent(ids(&["A", "C"])).score(&monitors),
+            None
+        );
+    }
+
+    #[test]
+    fn exact_rule_requires_same_set() {
+        let monitors = vec![monitor("A", 1920, 1080), monitor("B", 1920, 1080)];
+
+        assert_eq!(
+            ProfileRule::MonitorsExactly(ids(&["B", "A"])).score(&monitors),
+            Some(250)
+        );
+        assert_eq!(
+            ProfileRule::MonitorsExactly(ids(&["A"])).score(&monitors),
+            None
+        );
+    }
+
+    #[test]
+    fn ultrawide_detection() {
+        assert!(is_ultrawide(&monitor("UW", 3440, 1440)));
+        assert!(!is_ultrawide(&monitor("HD", 1920, 1080)));
+
+        let rule = ProfileRule::AnyUltrawide;
+        assert!(rule.score(&[monitor("HD", 1920, 1080)]).is_none());
+        assert!(
+            rule.score(&[monitor("HD", 1920, 1080), monitor("UW", 3440, 1440)])
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn all_rules_must_match() {
+        let monitors = vec![monitor("A", 1920, 1080)];
+        let p = profile(
+            "mixed",
+            vec![
+                ProfileRule::MonitorsPresent(ids(&["A"])),
+                ProfileRule::AnyUltrawide,
+            ],
+        );
+
+        assert_eq!(p.score(&monitors), None);
+    }
+
+    #[test]
+    fn most_specific_profile_wins() {
+        let office = vec![
+            monitor("A", 1920, 1080),
+            monitor("B", 1920, 1080),
+            monitor("C", 2560, 1440),
+        ];
+        let laptop = vec![monitor("LAPTOP", 1920, 1200)];
+
+        let profiles = vec![
+            profile("fallback", vec![]),
+            profile("three screens", vec![ProfileRule::MonitorCount(3)]),
+            profile(
+                "office",
+                vec![ProfileRule::MonitorsExactly(ids(&["A", "B", "C"]))],
+            ),
+        ];
+
+        assert_eq!(select_profile(&profiles, &office).unwrap().name, "office");
+        assert_eq!(select_profile(&profiles, &laptop).unwrap().name, "fallback");
+    }
+
+    #[test]
+    fn ties_go_to_first_profile() {
+        let monitors = vec![monitor("A", 1920, 1080)];
+        let profiles = vec![
+            profile("first", vec![ProfileRule::MonitorCount(1)]),
+            profile("second", vec![ProfileRule::MonitorCount(1)]),
+        ];
+
+        assert_eq!(select_profile(&profiles, &monitors).unwrap().name, "first");
+    }
+
+    #[test]
+    fn no_matching_profile() {
+        let monitors = vec![monitor("A", 1920, 1080)];
+        let profiles = vec![profile("two", vec![ProfileRule::MonitorCount(2)])];
+
+        assert!(select_profile(&profiles, &monitors).is_none());
+    }
+
+    #[test]
+    fn monitor_override_wins_over_profile_grid() {
+        let mut p = profile("home", vec![]);
+        p.grid = Some(GridConfig::new(4, 2));
+        p.monitor_grids
+            .insert(MonitorId::new("A"), GridConfig::new(2, 2));
+
+        assert_eq!(
+            p.grid_for(&monitor("A", 1920, 1080)),
+            Some(GridConfig::new(2, 2))
+        );
+        assert_eq!(
+            p.grid_for(&monitor("B", 1920, 1080)),
+            Some(GridConfig::new(4, 2))
+        );
+    }
+
+    #[test]
+    fn portrait_monitor_uses_portrait_grid() {
+        let mut p = profile("home", vec![]);
+        p.grid = Some(GridConfig::new(4, 2));
+
+        // No portrait grid configured: defer to the global portrait default
+        assert_eq!(p.grid_for(&monitor("P", 1080, 1920)), None);
+
+        p.portrait_grid = Some(GridConfig::new(2, 3));
+        assert_eq!(
+            p.grid_for(&monitor("P", 1080, 1920)),
+            Some(GridConfig::new(2, 3))
+        );
+        assert_eq!(
+            p.grid_for(&monitor("L", 1920, 1080)),
+            Some(GridConfig::new(4, 2))
+        );
+    }
+
+    #[test]
+    fn rules_deserialize_from_json() {
+        let json = r#"{
+            "name": "office",
+            "rules": [{ "monitors_present": ["A", "B"] }, "any_ultrawide", { "monitor_count": 3 }],
+            "grid": "4x2"
+        }"#;
+
+        let p: MonitorProfile = serde_json::from_str(json).unwrap();
+        assert_eq!(p.rules.len(), 3);
+        assert_eq!(p.rules[1], ProfileRule::AnyUltrawide);
+        assert_eq!(p.grid, Some(GridConfig::new(4, 2)));
+        assert!(p.monitor_grids.is_empty());
+    }
+}
@@ -0,0 +1,434 @@
+//! Saved desktop arrangements (sessions)
+//!
+//! A session records where every placeable window is, so the arrangement can
+//! be restored later. Windows are matched back by process, class and title
+//! since window handles do not survive restarts. Positions are kept both in
+//! pixels and as fractions of the owning monitor's work area, keyed by
+//! `MonitorId`, so a session still restores sensibly after a resolution
+//! change or on a different monitor.
+//!
+//! Sessions are stored as `%APPDATA%\tactile-win\sessions\<name>.json`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::storage::{self, ConfigError};
+use crate::domain::core::Rect;
+use crate::domain::monitor_transfer;
+use crate::platform::monitor_id::MonitorId;
+use crate::platform::monitors::Monitor;
+use crate::platform::window::WindowInfo;
+
+/// Name of the sessions directory inside the application directory
+const SESSIONS_DIR_NAME: &str = "sessions";
+
+/// Errors that can occur while saving or loading sessions
+#[derive(Debug, thiserror::Error)]
+pub enum SessionError {
+    #[error("Invalid session name '{0}' (use letters, digits, '-' and '_')")]
+    InvalidName(String),
+
+    #[error("Session '{0}' does not exist")]
+    NotFound(String),
+
+    #[error("Session storage failed: {0}")]
+    Storage(#[from] ConfigError),
+}
+
+impl From<std::io::Error> for SessionError {
+    fn from(err: std::io::Error) -> Self {
+        SessionError::Storage(ConfigError::Io(err))
+    }
+}
+
+impl From<serde_json::Error> for SessionError {
+    fn from(err: serde_json::Error) -> Self {
+        SessionError::Storage(ConfigError::Parse(err))
+    }
+}
+
+/// Rectangle expressed as fractions of a reference area
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FractionalRect {
+    pub x: f64,
+    pub y: f64,
+    pub w: f64,
+    pub h: f64,
+}
+
+impl FractionalRect {
+    /// Expresses a rectangle relative to an area (0.0 = left/top edge, 1.0 = right/bottom edge)
+    pub fn of(rect: Rect, area: Rect) -> Self {
+        let width = f64::from(area.w.max(1));
+        let height = f64::from(area.h.max(1));
+        Self {
+            x: f64::from(rect.x - area.x) / width,
+            y: f64::from(rect.y - area.y) / height,
+            w: f64::from(rect.w) / width,
+            h: f64::from(rect.h) / height,
+        }
+    }
+
+    /// Converts the fractions back into pixels within an area
+    pub fn to_rect(self, area: Rect) -> Rect {
+        let width = f64::from(area.w);
+        let height = f64::from(area.h);
+        Rect::new(
+            area.x + (self.x * width).round() as i32,
+            area.y + (self.y * height).round() as i32,
+            ((self.w * width).round() as i32).max(1),
+            ((self.h * height).round() as i32).max(1),
+        )
+    }
+}
+
+/// One window of a saved session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionWindow {
+    /// Executable name of the owning process
+    pub process: String,
+    /// Window class name
+    pub class: String,
+    /// Window title when the session was saved
+    pub title: String,
+    /// Monitor the window was on
+    pub monitor: MonitorId,
+    /// Work area of that monitor when the session was saved
+    pub work_area: Rect,
+    /// Visible frame of the window in pixels
+    pub rect: Rect,
+    /// Visible frame as fractions of the work area
+    pub fraction: FractionalRect,
+    /// Whether the window was maximized
+    pub maximized: bool,
+}
+
+impl SessionWindow {
+    /// Captures a window for a session
+    ///
+    /// # Arguments
+    /// * `info` - Window to capture
+    /// * `visible_rect` - Visible frame of the window (without invisible borders)
+    /// * `monitor` - Monitor that holds most of the window
+    pub fn capture(info: &WindowInfo, visible_rect: Rect, monitor: &Monitor) -> Self {
+        Self {
+            process: info.process_name.clone(),
+            class: info.class_name.clone(),
+            title: info.title.clone(),
+            monitor: monitor.id.clone(),
+            work_area: monitor.work_area,
+            rect: visible_rect,
+            fraction: FractionalRect::of(visible_rect, monitor.work_area),
+            maximized: info.is_maximized,
+        }
+    }
+
+    /// Scores how well a current window matches this saved window
+    ///
+    /// The process must match (when known) and the class must match; an
+    /// identical title makes the match stronger, which tells apart several
+    /// windows of the same application.
+    ///
+    /// # Returns
+    /// The match score (higher is better), or None if the window cannot match
+    pub fn match_score(&self, window: &WindowInfo) -> Option<u32> {
+        let process_known = !self.process.is_empty() && !window.process_name.is_empty();
+        if process_known && !self.process.eq_ignore_ascii_case(&window.process_name) {
+            return None;
+        }
+        if !self.class.eq_ignore_ascii_case(&window.class_name) {
+            return None;
+        }
+
+        let mut score = 1;
+        if process_known {
+            score += 2;
+        }
+        if self.title == window.title {
+            score += 4;
+        }
+        Some(score)
+    }
+
+    /// Computes where the window should go on the current monitors
+    ///
+    /// The pixel rectangle is used when the monitor is present with the same
+    /// work area. After a resolution change the fractions are applied to the
+    /// new work area; if the monitor is gone they are applied to the primary
+    /// monitor.
+    pub fn restore_rect(&self, monitors: &[Monitor]) -> Rect {
+        if let Some(monitor) = monitors.iter().find(|m| m.id == self.monitor) {
+            return if monitor.work_area == self.work_area {
+                self.rect
+            } else {
+                self.fraction.to_rect(monitor.work_area)
+            };
+        }
+
+        monitors
+            .iter()
+            .find(|m| m.is_primary)
+            .or_else(|| monitors.first())
+            .map_or(self.rect, |m| self.fraction.to_rect(m.work_area))
+    }
+}
+
+/// A named, saved desktop arrangement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub name: String,
+    pub windows: Vec<SessionWindow>,
+}
+
+/// Pairs saved windows with current windows
+///
+/// Best-scoring pairs are taken first and every window is used at most once,
+/// so the result does not depend on enumeration order for clear matches.
+///
+/// # Returns
+/// (saved index, current index) pairs, ordered by saved index
+pub fn match_windows(saved: &[SessionWindow], current: &[WindowInfo]) -> Vec<(usize, usize)> {
+    let mut candidates: Vec<(u32, usize, usize)> = saved
+        .iter()
+        .enumerate()
+        .flat_map(|(s, saved_window)| {
+            current.iter().enumerate().filter_map(move |(c, window)| {
+                saved_window.match_score(window).map(|score| (score, s, c))
+            })
+        })
+        .collect();
+    candidates.sort_by_key(|&(score, s, c)| (std::cmp::Reverse(score), s, c));
+
+    let mut saved_used = vec![false; saved.len()];
+    let mut current_used = vec![false; current.len()];
+    let mut pairs = Vec::new();
+    for (_, s, c) in candidates {
+        if !saved_used[s] && !current_used[c] {
+            saved_used[s] = true;
+            current_used[c] = true;
+            pairs.push((s, c));
+        }
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Finds the monitor that holds the largest part of a rectangle
+pub fn owning_monitor(rect: Rect, monitors: &[Monitor]) -> Option<&Monitor> {
+    let work_areas: Vec<Rect> = monitors.iter().map(|m| m.work_area).collect();
+    monitor_transfer::area_containing(rect, &work_areas).map(|index| &monitors[index])
+}
+
+/// Returns the sessions directory (`%APPDATA%\tactile-win\sessions`)
+pub fn sessions_dir() -> Result<PathBuf, ConfigError> {
+    Ok(storage::app_data_dir()?.join(SESSIONS_DIR_NAME))
+}
+
+/// Returns true if a session name is safe to use as a file name
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn session_path(dir: &Path, name: &str) -> Result<PathBuf, SessionError> {
+    if !is_valid_name(name) {
+        return Err(SessionError::InvalidName(name.to_string()));
+    }
+    Ok(dir.join(format!("{}.json", name)))
+}
+
+/// Saves a session into a directory, replacing any session with the same name
+pub fn save_session(session: &Session, dir: &Path) -> Result<(), SessionError> {
+    let path = session_path(dir, &session.name)?;
+    fs::create_dir_all(dir)?;
+    fs::write(path, serde_json::to_string_pretty(session)?)?;
+    Ok(())
+}
+
+/// Loads a named session from a directory
+pub fn load_session(name: &str, dir: &Path) -> Result<Session, SessionError> {
+    let path = session_path(dir, name)?;
+    if !path.exists() {
+        return Err(SessionError::NotFound(name.to_string()));
+    }
+
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Gdi::HMONITOR;
+
+    fn monitor(id: &str, work_area: Rect, is_primary: bool) -> Monitor {
+        Monitor {
+            handle: HMONITOR(0),
+            index: 0,
+            id: MonitorId::new(id),
+            physical_rect: Rect::new(work_area.x, work_area.y, work_area.w, work_area.h + 40),
+            work_area,
+            dpi_scale: 1.0,
+            dpi_x: 96,
+            dpi_y: 96,
+            is_primary,
+        }
+    }
+
+    fn window(process: &str, class: &str, title: &str) -> WindowInfo {
+        WindowInfo {
+            handle: HWND(0),
+            title: title.to_string(),
+            class_name: class.to_string(),
+            process_name: process.to_string(),
+            rect: Rect::new(0, 0, 800, 600),
+            normal_rect: Rect::new(0, 0, 800, 600),
+            is_resizable: true,
+            has_caption: true,
+            is_child: false,
+            is_maximized: false,
+        }
+    }
+
+    fn saved(process: &str, class: &str, title: &str) -> SessionWindow {
+        let work_area = Rect::new(0, 0, 1920, 1040);
+        let info = window(process, class, title);
+        SessionWindow::capture(
+            &info,
+            Rect::new(960, 0, 960, 520),
+            &monitor("DELL", work_area, true),
+        )
+    }
+
+    #[test]
+    fn fractions_round_trip() {
+        let area = Rect::new(1920, 0, 2560, 1400);
+        let rect = Rect::new(2560, 350, 1280, 700);
+
+        let fraction = FractionalRect::of(rect, area);
+        assert_eq!((fraction.x, fraction.y), (0.25, 0.25));
+        assert_eq!((fraction.w, fraction.h), (0.5, 0.5));
+        assert_eq!(fraction.to_rect(area), rect);
+    }
+
+    #[test]
+    fn same_monitor_restores_pixels() {
+        let session_window = saved("code.exe", "Chrome_WidgetWin_1", "main.rs");
+        let monitors = [monitor("DELL", Rect::new(0, 0, 1920, 1040), true)];
+
+        assert_eq!(
+            session_window.restore_rect(&monitors),
+            Rect::new(960, 0, 960, 520)
+        );
+    }
+
+    #[test]
+    fn resolution_change_restores_fractions() {
+        let session_window = saved("code.exe", "Chrome_WidgetWin_1", "main.rs");
+        let monitors = [monitor("DELL", Rect::new(0, 0, 2560, 1400), true)];
+
+        assert_eq!(
+            session_window.restore_rect(&monitors),
+            Rect::new(1280, 0, 1280, 700)
+        );
+    }
+
+    #[test]
+    fn missing_monitor_falls_back_to_primary() {
+        let session_window = saved("code.exe", "Chrome_WidgetWin_1", "main.rs");
+        let monitors = [
+            monitor("LG", Rect::new(-1280, 0, 1280, 984), false),
+            monitor("LAPTOP", Rect::new(0, 0, 1280, 760), true),
+        ];
+
+        assert_eq!(
+            session_window.restore_rect(&monitors),
+            Rect::new(640, 0, 640, 380)
+        );
+    }
+
+    #[test]
+    fn matching_requires_process_and_class() {
+        let session_window = saved("code.exe", "Chrome_WidgetWin_1", "main.rs");
+
+        assert!(
+            session_window
+                .match_score(&window("chrome.exe", "Chrome_WidgetWin_1", "main.rs"))
+                .is_none()
+        );
+        assert!(
+            session_window
+                .match_score(&window("code.exe", "Other", "main.rs"))
+                .is_none()
+        );
+        assert!(
+            session_window
+                .match_score(&window("CODE.EXE", "Chrome_WidgetWin_1", "lib.rs"))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn title_tells_apart_windows_of_same_app() {
+        let saved_windows = [
+            saved("code.exe", "Chrome_WidgetWin_1", "server - Code"),
+            saved("code.exe", "Chrome_WidgetWin_1", "client - Code"),
+        ];
+        let current = [
+            window("code.exe", "Chrome_WidgetWin_1", "client - Code"),
+            window("code.exe", "Chrome_WidgetWin_1", "server - Code"),
+        ];
+
+        assert_eq!(
+            match_windows(&saved_windows, &current),
+            vec![(0, 1), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn windows_are_matched_at_most_once() {
+        let saved_windows = [
+            saved("notepad.exe", "Notepad", "a.txt"),
+            saved("notepad.exe", "Notepad", "b.txt"),
+        ];
+        let current = [window("notepad.exe", "Notepad", "c.txt")];
+
+        assert_eq!(match_windows(&saved_windows, &current), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn session_names_are_validated() {
+        assert!(is_valid_name("work-2_monitors"));
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name("../config"));
+        assert!(!is_valid_name("a b"));
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = std::env::temp_dir().join(format!("tactile-win-sessions-{}", std::process::id()));
+        let session = Session {
+            name: "work".to_string(),
+            windows: vec![saved("code.exe", "Chrome_WidgetWin_1", "main.rs")],
+        };
+
+        save_session(&session, &dir).unwrap();
+        assert_eq!(load_session("work", &dir).unwrap(), session);
+        assert!(matches!(
+            load_session("missing", &dir),
+            Err(SessionError::NotFound(_))
+        ));
+        assert!(matches!(
+            load_session("../work", &dir),
+            Err(SessionError::InvalidName(_))
+        ));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
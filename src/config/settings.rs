@@ -0,0 +1,291 @@
+//! Configuration schema
+//!
+//! Defines all user settings with serde-friendly representations and
+//! sensible defaults, so a partial or missing config file still yields
+//! a usable configuration.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::hotkeys::HotkeyConfig;
+use crate::config::profiles::{self, MonitorProfile};
+use crate::config::window_rules::{AutoPlaceRule, WindowRule};
+use crate::domain::cross_monitor_selection::CrossMonitorPolicy;
+use crate::domain::master_stack::MasterStackConfig;
+use crate::domain::placement::PlacementOptions;
+use crate::domain::rescue::RescueOptions;
+use crate::platform::monitors::Monitor;
+use crate::platform::orientation::Orientation;
+use crate::ui::theme::Theme;
+
+/// Errors that can occur while parsing configuration values
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SettingsError {
+    #[error("Invalid grid specification '{0}' (expected COLSxROWS, e.g. \"3x2\")")]
+    InvalidGridSpec(String),
+}
+
+/// Grid dimensions, written as "COLSxROWS" in the config file (e.g. "3x2")
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct GridConfig {
+    pub cols: u32,
+    pub rows: u32,
+}
+
+impl GridConfig {
+    /// Creates a grid configuration
+    pub fn new(cols: u32, rows: u32) -> Self {
+        Self { cols, rows }
+    }
+
+    /// Returns the grid with rows and columns swapped (e.g. 3x2 becomes 2x3)
+    pub fn transposed(self) -> Self {
+        Self::new(self.rows, self.cols)
+    }
+}
+
+impl Default for GridConfig {
+    /// The default 3x2 grid (Q W E / A S D)
+    fn default() -> Self {
+        Self::new(3, 2)
+    }
+}
+
+impl FromStr for GridConfig {
+    type Err = SettingsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SettingsError::InvalidGridSpec(s.to_string());
+        let (cols, rows) = s.trim().split_once(['x', 'X']).ok_or_else(invalid)?;
+        let cols: u32 = cols.trim().parse().map_err(|_| invalid())?;
+        let rows: u32 = rows.trim().parse().map_err(|_| invalid())?;
+
+        if cols == 0 || rows == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(cols, rows))
+    }
+}
+
+impl TryFrom<String> for GridConfig {
+    type Error = SettingsError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<GridConfig> for String {
+    fn from(grid: GridConfig) -> Self {
+        grid.to_string()
+    }
+}
+
+impl std::fmt::Display for GridConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.cols, self.rows)
+    }
+}
+
+/// Complete application configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Grid used on landscape and square monitors that no active profile overrides
+    pub grid: GridConfig,
+    /// Grid used on portrait monitors that no active profile overrides
+    pub portrait_grid: GridConfig,
+    /// Monitor profiles, matched against the connected display set
+    pub profiles: Vec<MonitorProfile>,
+    /// How selections spanning two monitors handle DPI and edge mismatches
+    pub cross_monitor_policy: CrossMonitorPolicy,
+    /// How windows are fitted into the selected area
+    pub placement: PlacementOptions,
+    /// Per-application rules, checked in order before placing a window
+    pub rules: Vec<WindowRule>,
+    /// Where newly opened windows are placed automatically, checked in order
+    pub auto_place: Vec<AutoPlaceRule>,
+    /// Master/stack tiling mode settings
+    pub master_stack: MasterStackConfig,
+    /// Gathering of windows left off-screen
+    pub rescue: RescueOptions,
+    /// Name of the overlay theme: a user theme from `themes` or a built-in one
+    pub theme: String,
+    /// User-defined overlay themes by name
+    pub themes: BTreeMap<String, Theme>,
+    /// Global hotkey bindings
+    pub hotkeys: HotkeyConfig,
+    /// Name of the session saved and restored by the session hotkeys
+    pub session_name: String,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        let grid = GridConfig::default();
+        Self {
+            grid,
+            // Transposed so portrait cells keep a usable width (Q W / A S / Z X)
+            portrait_grid: grid.transposed(),
+            profiles: Vec::new(),
+            cross_monitor_policy: CrossMonitorPolicy::default(),
+            placement: PlacementOptions::default(),
+            rules: Vec::new(),
+            auto_place: Vec::new(),
+            master_stack: MasterStackConfig::default(),
+            rescue: RescueOptions::default(),
+            theme: "default".to_string(),
+            themes: BTreeMap::new(),
+            hotkeys: HotkeyConfig::default(),
+            session_name: "default".to_string(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Selects the profile that best matches the connected monitors
+    ///
+    /// # Returns
+    /// The best-scoring profile, or None if no profile matches
+    pub fn active_profile(&self, monitors: &[Monitor]) -> Option<&MonitorProfile> {
+        profiles::select_profile(&self.profiles, monitors)
+    }
+
+    /// Resolves the configured overlay theme
+    ///
+    /// User themes take precedence over built-in themes of the same name.
+    ///
+    /// # Returns
+    /// The theme, or None if no theme has the configured name
+    pub fn overlay_theme(&self) -> Option<Theme> {
+        self.themes
+            .get(&self.theme)
+            .copied()
+            .or_else(|| Theme::builtin(&self.theme))
+    }
+
+    /// Returns the default grid for a monitor orientation
+    pub fn grid_for_orientation(&self, orientation: Orientation) -> GridConfig {
+        match orientation {
+            Orientation::Portrait => self.portrait_grid,
+            Orientation::Landscape | Orientation::Square => self.grid,
+        }
+    }
+
+    /// Resolves the grid to use for a monitor under the given profile
+    ///
+    /// Per-monitor profile overrides win over the profile grid for the
+    /// monitor's orientation, which wins over the global default.
+    pub fn grid_for_monitor(
+        &self,
+        profile: Option<&MonitorProfile>,
+        monitor: &Monitor,
+    ) -> GridConfig {
+        profile
+            .and_then(|p| p.grid_for(monitor))
+            .unwrap_or_else(|| self.grid_for_orientation(monitor.orientation()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::animation::{AnimationOptions, Easing};
+
+    #[test]
+    fn grid_config_parsing() {
+        assert_eq!("3x2".parse::<GridConfig>().unwrap(), GridConfig::new(3, 2));
+        assert_eq!(
+            " 4X3 ".parse::<GridConfig>().unwrap(),
+            GridConfig::new(4, 3)
+        );
+
+        assert!("3".parse::<GridConfig>().is_err());
+        assert!("0x2".parse::<GridConfig>().is_err());
+        assert!("ax2".parse::<GridConfig>().is_err());
+    }
+
+    #[test]
+    fn grid_config_round_trips_as_string() {
+        let json = serde_json::to_string(&GridConfig::new(4, 2)).unwrap();
+        assert_eq!(json, "\"4x2\"");
+        assert_eq!(
+            serde_json::from_str::<GridConfig>(&json).unwrap(),
+            GridConfig::new(4, 2)
+        );
+    }
+
+    #[test]
+    fn empty_config_uses_defaults() {
+        let config: AppConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, AppConfig::default());
+        assert_eq!(config.grid, GridConfig::new(3, 2));
+        assert_eq!(config.portrait_grid, GridConfig::new(2, 3));
+        assert_eq!(config.session_name, "default");
+
+        let config: AppConfig = serde_json::from_str(r#"{ "session_name": "work" }"#).unwrap();
+        assert_eq!(config.session_name, "work");
+    }
+
+    #[test]
+    fn partial_animation_settings_keep_defaults() {
+        let json =
+            r#"{ "placement": { "animation": { "enabled": true, "easing": "ease_in_out" } } }"#;
+        let config: AppConfig = serde_json::from_str(json).unwrap();
+        let animation = config.placement.animation;
+        assert!(animation.enabled);
+        assert_eq!(animation.easing, Easing::EaseInOut);
+        assert_eq!(
+            animation.duration_ms,
+            AnimationOptions::default().duration_ms
+        );
+        assert!(!AppConfig::default().placement.animation.enabled);
+    }
+
+    #[test]
+    fn overlay_theme_resolution() {
+        assert_eq!(AppConfig::default().overlay_theme(), Some(Theme::default()));
+
+        let json = r##"{
+            "theme": "light",
+            "themes": { "mine": { "label": "#FFCC00" } }
+        }"##;
+        let mut config: AppConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.overlay_theme(), Some(Theme::light()));
+
+        config.theme = "mine".to_string();
+        let theme = config.overlay_theme().unwrap();
+        assert_eq!(theme.label.to_string(), "#FFCC00FF");
+        assert_eq!(theme.grid_line, Theme::default().grid_line);
+
+        // User themes shadow built-in ones
+        config
+            .themes
+            .insert("light".to_string(), Theme::high_contrast());
+        config.theme = "light".to_string();
+        assert_eq!(config.overlay_theme(), Some(Theme::high_contrast()));
+
+        config.theme = "missing".to_string();
+        assert_eq!(config.overlay_theme(), None);
+    }
+
+    #[test]
+    fn orientation_selects_default_grid() {
+        let config = AppConfig::default();
+        assert_eq!(
+            config.grid_for_orientation(Orientation::Landscape),
+            GridConfig::new(3, 2)
+        );
+        assert_eq!(
+            config.grid_for_orientation(Orientation::Square),
+            GridConfig::new(3, 2)
+        );
+        assert_eq!(
+            config.grid_for_orientation(Orientation::Portrait),
+            GridConfig::new(2, 3)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Configuration persistence
+//!
+//! Loads and saves the JSON configuration file from the user directory
+//! (`%APPDATA%\tactile-win\config.json`). A missing file is not an error:
+//! defaults are used instead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::settings::AppConfig;
+
+/// Name of the application directory inside %APPDATA%
+const APP_DIR_NAME: &str = "tactile-win";
+/// Name of the configuration file
+const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Errors that can occur while loading or saving configuration
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Could not determine the user configuration directory")]
+    NoConfigDirectory,
+
+    #[error("Configuration I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Configuration file is invalid: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Returns the application data directory (`%APPDATA%\tactile-win`)
+pub fn app_data_dir() -> Result<PathBuf, ConfigError> {
+    std::env::var_os("APPDATA")
+        .map(|dir| PathBuf::from(dir).join(APP_DIR_NAME))
+        .ok_or(ConfigError::NoConfigDirectory)
+}
+
+/// Returns the full path of the configuration file
+pub fn config_path() -> Result<PathBuf, ConfigError> {
+    Ok(app_data_dir()?.join(CONFIG_FILE_NAME))
+}
+
+/// Loads configuration from the given file
+///
+/// # Returns
+/// The parsed configuration, defaults if the file does not exist,
+/// or ConfigError if it cannot be read or parsed
+pub fn load_from(path: &Path) -> Result<AppConfig, ConfigError> {
+    if !path.exists() {
+        return Ok(AppConfig::default());
+    }
+
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Saves configuration to the given file, creating parent directories as needed
+#[cfg(test)]
+pub fn save_to(config: &AppConfig, path: &Path) -> Result<(), ConfigError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let contents = serde_json::to_string_pretty(config)?;
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Loads the user configuration, falling back to defaults on any error
+///
+/// **Resilience Strategy**: A corrupt or unreadable config file must never
+/// prevent the application from starting, so errors are logged and defaults used.
+pub fn load_or_default() -> AppConfig {
+    let result = config_path().and_then(|path| {
+        println!("Config: Loading {}", path.display());
+        load_from(&path)
+    });
+
+    match result {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Config: {} - using defaults", e);
+            AppConfig::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::settings::GridConfig;
+
+    fn temp_config_path(test_name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("tactile-win-{}-{}", test_name, std::process::id()))
+            .join(CONFIG_FILE_NAME)
+    }
+
+    #[test]
+    fn missing_file_yields_defaults() {
+        let path = temp_config_path("missing");
+        assert_eq!(load_from(&path).unwrap(), AppConfig::default());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = temp_config_path("round-trip");
+        let config = AppConfig {
+            grid: GridConfig::new(4, 2),
+            ..AppConfig::default()
+        };
+
+        save_to(&config, &path).unwrap();
+        assert_eq!(load_from(&path).unwrap(), config);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn invalid_json_is_reported() {
+        let path = temp_config_path("invalid");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{ not json").unwrap();
+
+        assert!(matches!(load_from(&path), Err(ConfigError::Parse(_))));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
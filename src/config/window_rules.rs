@@ -0,0 +1,470 @@
+//! Per-application window rules
+//!
+//! Rules match windows by process name, window class and title regex, and
+//! change how (or whether) they are placed. Auto-placement rules put newly
+//! opened windows into a grid area. Matching is a pure function of
+//! `WindowInfo` so it can be tested with synthetic windows.
+//!
+//! ## Matching
+//! Every criterion a rule specifies must match; a rule without criteria never
+//! matches. Rules are checked in order and the first match wins. Shell windows
+//! (desktop, taskbar) and full-screen windows are always ignored.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::settings::GridConfig;
+use crate::domain::core::Rect;
+use crate::platform::monitor_id::MonitorId;
+use crate::platform::window::WindowInfo;
+
+/// Window classes of shell surfaces that must never be placed
+pub const BUILTIN_IGNORED_CLASSES: &[&str] = &[
+    "Progman",                // Desktop
+    "WorkerW",                // Desktop (wallpaper host)
+    "Shell_TrayWnd",          // Primary taskbar
+    "Shell_SecondaryTrayWnd", // Taskbar on other monitors
+];
+
+/// Regular expression matched against window titles
+///
+/// Written as a plain string in the config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TitlePattern(regex::Regex);
+
+impl TitlePattern {
+    /// Compiles a title pattern
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Self)
+    }
+
+    /// Returns true if the title matches the pattern
+    pub fn is_match(&self, title: &str) -> bool {
+        self.0.is_match(title)
+    }
+}
+
+impl PartialEq for TitlePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl TryFrom<String> for TitlePattern {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl From<TitlePattern> for String {
+    fn from(pattern: TitlePattern) -> Self {
+        pattern.0.as_str().to_string()
+    }
+}
+
+/// What a matching rule does to a window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum RuleAction {
+    /// Never place the window
+    Ignore,
+    /// Always move the window keeping its size, even if it is resizable
+    MoveOnly,
+    /// Leave this many pixels free on every side of the selected area
+    Gap { pixels: i32 },
+    /// Show this grid layout on all monitors when the window is the target
+    Layout { grid: GridConfig },
+}
+
+/// Criteria identifying windows, shared by all rule kinds
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WindowMatch {
+    /// Executable name of the owning process (case-insensitive, e.g. "game.exe")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process: Option<String>,
+    /// Window class name (case-insensitive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Regular expression matched against the window title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<TitlePattern>,
+}
+
+impl WindowMatch {
+    /// Returns true if every criterion matches the window
+    pub fn matches(&self, window: &WindowInfo) -> bool {
+        if self.process.is_none() && self.class.is_none() && self.title.is_none() {
+            return false;
+        }
+
+        let process_matches = self
+            .process
+            .as_ref()
+            .is_none_or(|p| p.eq_ignore_ascii_case(&window.process_name));
+        let class_matches = self
+            .class
+            .as_ref()
+            .is_none_or(|c| c.eq_ignore_ascii_case(&window.class_name));
+        let title_matches = self
+            .title
+            .as_ref()
+            .is_none_or(|t| t.is_match(&window.title));
+
+        process_matches && class_matches && title_matches
+    }
+}
+
+/// A window rule: match criteria plus the action to apply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowRule {
+    /// Windows the rule applies to
+    #[serde(flatten)]
+    pub criteria: WindowMatch,
+    /// Action applied to matching windows
+    #[serde(flatten)]
+    pub action: RuleAction,
+}
+
+impl WindowRule {
+    /// Returns true if the rule applies to the window
+    pub fn matches(&self, window: &WindowInfo) -> bool {
+        self.criteria.matches(window)
+    }
+}
+
+/// Errors that can occur while parsing a key range
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum KeyRangeError {
+    #[error("Invalid key range '{0}' (expected a key or two keys, e.g. \"Q-A\")")]
+    Invalid(String),
+}
+
+/// Grid area given by its corner keys, written as "Q-A" (or "Q" for one cell)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyRange {
+    pub start: char,
+    pub end: char,
+}
+
+impl KeyRange {
+    /// Creates a key range from its corner keys
+    pub fn new(start: char, end: char) -> Self {
+        Self {
+            start: start.to_ascii_uppercase(),
+            end: end.to_ascii_uppercase(),
+        }
+    }
+}
+
+impl FromStr for KeyRange {
+    type Err = KeyRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || KeyRangeError::Invalid(s.to_string());
+        let single_key = |part: &str| {
+            let mut chars = part.trim().chars();
+            match (chars.next(), chars.next()) {
+                (Some(key), None) if key.is_ascii_alphabetic() => Ok(key),
+                _ => Err(invalid()),
+            }
+        };
+
+        match s.split_once('-') {
+            Some((start, end)) => Ok(Self::new(single_key(start)?, single_key(end)?)),
+            None => {
+                let key = single_key(s)?;
+                Ok(Self::new(key, key))
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for KeyRange {
+    type Error = KeyRangeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<KeyRange> for String {
+    fn from(range: KeyRange) -> Self {
+        format!("{}-{}", range.start, range.end)
+    }
+}
+
+/// Places newly opened windows into a grid area
+///
+/// # Example
+/// ```json
+/// { "process": "slack.exe", "monitor": "DELL-P2419H", "layout": "3x1", "keys": "E" }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoPlaceRule {
+    /// Windows the rule applies to
+    #[serde(flatten)]
+    pub criteria: WindowMatch,
+    /// Monitor to place the window on (primary monitor if unset or not connected)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitor: Option<MonitorId>,
+    /// Grid layout the keys refer to
+    pub layout: GridConfig,
+    /// Corner keys of the target area within the layout
+    pub keys: KeyRange,
+}
+
+/// Finds the auto-placement rule for a window
+///
+/// # Returns
+/// Index of the first matching rule, or None
+pub fn auto_place_rule_for(rules: &[AutoPlaceRule], window: &WindowInfo) -> Option<usize> {
+    rules.iter().position(|rule| rule.criteria.matches(window))
+}
+
+/// Resolves the action that applies to a window
+///
+/// # Arguments
+/// * `rules` - User rules, in priority order
+/// * `window` - Window to check
+/// * `visible_rect` - Visible frame of the window, without invisible borders
+/// * `monitor_rects` - Full bounds of the connected monitors, for full-screen detection
+///
+/// # Returns
+/// The action to apply, or None if the window is placed normally
+pub fn action_for(
+    rules: &[WindowRule],
+    window: &WindowInfo,
+    visible_rect: Rect,
+    monitor_rects: &[Rect],
+) -> Option<RuleAction> {
+    let is_shell = BUILTIN_IGNORED_CLASSES
+        .iter()
+        .any(|class| class.eq_ignore_ascii_case(&window.class_name));
+    if is_shell || is_fullscreen(window, visible_rect, monitor_rects) {
+        return Some(RuleAction::Ignore);
+    }
+
+    rules
+        .iter()
+        .find(|rule| rule.matches(window))
+        .map(|rule| rule.action)
+}
+
+/// Returns true if a borderless, non-maximized window covers an entire monitor
+///
+/// Full-screen games and video players drop their frame and size themselves
+/// to the monitor instead of maximizing. Framed windows are never full-screen,
+/// even when tiled over a work area that spans the whole monitor.
+///
+/// # Arguments
+/// * `window` - Window to check
+/// * `visible_rect` - Visible frame of the window, without invisible borders
+/// * `monitor_rects` - Full bounds of the connected monitors
+pub fn is_fullscreen(window: &WindowInfo, visible_rect: Rect, monitor_rects: &[Rect]) -> bool {
+    let is_borderless = !window.has_caption && !window.is_resizable;
+    is_borderless
+        && !window.is_maximized
+        && monitor_rects
+            .iter()
+            .any(|monitor| visible_rect.contains_rect(monitor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use windows::Win32::Foundation::HWND;
+
+    fn window(process: &str, class: &str, title: &str) -> WindowInfo {
+        WindowInfo {
+            handle: HWND(0),
+            title: title.to_string(),
+            class_name: class.to_string(),
+            process_name: process.to_string(),
+            rect: Rect::new(100, 100, 800, 600),
+            normal_rect: Rect::new(100, 100, 800, 600),
+            is_resizable: true,
+            has_caption: true,
+            is_child: false,
+            is_maximized: false,
+        }
+    }
+
+    fn criteria(process: Option<&str>, class: Option<&str>, title: Option<&str>) -> WindowMatch {
+        WindowMatch {
+            process: process.map(str::to_string),
+            class: class.map(str::to_string),
+            title: title.map(|t| TitlePattern::new(t).unwrap()),
+        }
+    }
+
+    fn rule(process: Option<&str>, class: Option<&str>, title: Option<&str>) -> WindowRule {
+        WindowRule {
+            criteria: criteria(process, class, title),
+            action: RuleAction::Ignore,
+        }
+    }
+
+    const MONITORS: &[Rect] = &[Rect {
+        x: 0,
+        y: 0,
+        w: 1920,
+        h: 1080,
+    }];
+
+    #[test]
+    fn all_criteria_must_match() {
+        let notepad = window("notepad.exe", "Notepad", "todo.txt - Notepad");
+
+        assert!(rule(Some("NOTEPAD.EXE"), None, None).matches(&notepad));
+        assert!(rule(Some("notepad.exe"), Some("notepad"), Some(r"\.txt")).matches(&notepad));
+        assert!(!rule(Some("notepad.exe"), Some("Edit"), None).matches(&notepad));
+        assert!(!rule(None, None, Some("^Untitled")).matches(&notepad));
+    }
+
+    #[test]
+    fn rule_without_criteria_never_matches() {
+        assert!(!rule(None, None, None).matches(&window("a.exe", "A", "A")));
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rules = vec![
+            WindowRule {
+                action: RuleAction::MoveOnly,
+                ..rule(Some("calc.exe"), None, None)
+            },
+            WindowRule {
+                action: RuleAction::Gap { pixels: 8 },
+                ..rule(None, None, Some("Calc"))
+            },
+        ];
+
+        let calc = window("calc.exe", "ApplicationFrameWindow", "Calculator");
+        assert_eq!(
+            action_for(&rules, &calc, calc.rect, MONITORS),
+            Some(RuleAction::MoveOnly)
+        );
+
+        let other = window("other.exe", "Other", "Calculus notes");
+        assert_eq!(
+            action_for(&rules, &other, other.rect, MONITORS),
+            Some(RuleAction::Gap { pixels: 8 })
+        );
+
+        let unmatched = window("code.exe", "Chrome_WidgetWin_1", "main.rs");
+        assert_eq!(
+            action_for(&rules, &unmatched, unmatched.rect, MONITORS),
+            None
+        );
+    }
+
+    #[test]
+    fn shell_windows_are_always_ignored() {
+        let taskbar = window("explorer.exe", "Shell_TrayWnd", "");
+        assert_eq!(
+            action_for(&[], &taskbar, taskbar.rect, MONITORS),
+            Some(RuleAction::Ignore)
+        );
+    }
+
+    #[test]
+    fn fullscreen_windows_are_ignored() {
+        let mut game = window("game.exe", "UnityWndClass", "Game");
+        game.is_resizable = false;
+        game.has_caption = false;
+        game.rect = Rect::new(0, 0, 1920, 1080);
+        assert_eq!(
+            action_for(&[], &game, game.rect, MONITORS),
+            Some(RuleAction::Ignore)
+        );
+
+        // A maximized window overlapping the monitor edges is not full-screen
+        game.is_maximized = true;
+        game.rect = Rect::new(-8, -8, 1936, 1056);
+        assert!(!is_fullscreen(&game, game.rect, MONITORS));
+    }
+
+    #[test]
+    fn framed_window_over_whole_work_area_is_not_fullscreen() {
+        // Taskbar-less monitor: the work area is the whole monitor, and the
+        // window rect includes 7px invisible resize borders around it
+        let mut editor = window("code.exe", "Chrome_WidgetWin_1", "main.rs");
+        editor.rect = Rect::new(-7, 0, 1934, 1087);
+        let visible_rect = Rect::new(0, 0, 1920, 1080);
+
+        assert!(!is_fullscreen(&editor, visible_rect, MONITORS));
+        assert_eq!(action_for(&[], &editor, visible_rect, MONITORS), None);
+    }
+
+    #[test]
+    fn rules_deserialize_from_json() {
+        let json = r##"[
+            { "process": "game.exe", "action": "ignore" },
+            { "class": "#32770", "action": "move_only" },
+            { "title": "- Visual Studio Code$", "action": "gap", "pixels": 6 },
+            { "process": "excel.exe", "action": "layout", "grid": "4x2" }
+        ]"##;
+
+        let rules: Vec<WindowRule> = serde_json::from_str(json).unwrap();
+        assert_eq!(rules[0].action, RuleAction::Ignore);
+        assert_eq!(rules[1].criteria.class.as_deref(), Some("#32770"));
+        assert_eq!(rules[2].action, RuleAction::Gap { pixels: 6 });
+        assert!(
+            rules[2]
+                .criteria
+                .title
+                .as_ref()
+                .unwrap()
+                .is_match("main.rs - Visual Studio Code")
+        );
+        assert_eq!(
+            rules[3].action,
+            RuleAction::Layout {
+                grid: GridConfig::new(4, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn key_ranges_parse() {
+        assert_eq!("Q-A".parse(), Ok(KeyRange::new('Q', 'A')));
+        assert_eq!(" e - d ".parse(), Ok(KeyRange::new('E', 'D')));
+        assert_eq!("W".parse(), Ok(KeyRange::new('W', 'W')));
+        assert!("QA".parse::<KeyRange>().is_err());
+        assert!("Q-".parse::<KeyRange>().is_err());
+        assert!("1-2".parse::<KeyRange>().is_err());
+        assert_eq!(String::from(KeyRange::new('q', 'a')), "Q-A");
+    }
+
+    #[test]
+    fn auto_place_rules_deserialize_and_match() {
+        let json = r#"[
+            { "process": "slack.exe", "monitor": "DELL", "layout": "3x1", "keys": "E" },
+            { "title": "Spotify", "layout": "2x2", "keys": "W-S" }
+        ]"#;
+
+        let rules: Vec<AutoPlaceRule> = serde_json::from_str(json).unwrap();
+        assert_eq!(rules[0].monitor, Some(MonitorId::new("DELL")));
+        assert_eq!(rules[0].layout, GridConfig::new(3, 1));
+        assert_eq!(rules[0].keys, KeyRange::new('E', 'E'));
+        assert_eq!(rules[1].monitor, None);
+
+        let slack = window("Slack.exe", "Chrome_WidgetWin_1", "Slack | general");
+        let spotify = window("spotify.exe", "Chrome_WidgetWin_0", "Spotify Premium");
+        let other = window("code.exe", "Chrome_WidgetWin_1", "main.rs");
+        assert_eq!(auto_place_rule_for(&rules, &slack), Some(0));
+        assert_eq!(auto_place_rule_for(&rules, &spotify), Some(1));
+        assert_eq!(auto_place_rule_for(&rules, &other), None);
+    }
+
+    #[test]
+    fn invalid_title_regex_is_rejected() {
+        let json = r#"{ "title": "([unclosed", "action": "ignore" }"#;
+        assert!(serde_json::from_str::<WindowRule>(json).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Remembered placements per application
+//!
+//! Records the last grid selection applied to each application so it can
+//! be re-applied on demand. Applications are identified by their process
+//! name, or by window class when the process is unknown. The memory is
+//! stored next to the configuration (`%APPDATA%\tactile-win\zones.json`).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::settings::GridConfig;
+use crate::config::storage::{self, ConfigError};
+use crate::config::window_rules::KeyRange;
+use crate::platform::monitor_id::MonitorId;
+use crate::platform::window::WindowInfo;
+
+/// Name of the zone memory file inside the application data directory
+const ZONE_MEMORY_FILE_NAME: &str = "zones.json";
+
+/// Grid selection last applied to an application
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RememberedZone {
+    /// Monitor the selection was made on
+    pub monitor: MonitorId,
+    /// Grid layout the keys refer to
+    pub layout: GridConfig,
+    /// Corner keys of the selected area
+    pub keys: KeyRange,
+}
+
+/// Last zone of each application
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ZoneMemory {
+    /// Zones keyed by lowercase process name
+    processes: BTreeMap<String, RememberedZone>,
+    /// Zones of windows whose process is unknown, keyed by class name
+    classes: BTreeMap<String, RememberedZone>,
+}
+
+impl ZoneMemory {
+    /// Records the zone applied to a window, replacing its application's previous zone
+    ///
+    /// # Returns
+    /// false if the window cannot be attributed to an application
+    pub fn remember(&mut self, window: &WindowInfo, zone: RememberedZone) -> bool {
+        if !window.process_name.is_empty() {
+            self.processes
+                .insert(window.process_name.to_lowercase(), zone);
+        } else if !window.class_name.is_empty() {
+            self.classes.insert(window.class_name.clone(), zone);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Returns the zone last applied to the window's application
+    pub fn lookup(&self, window: &WindowInfo) -> Option<&RememberedZone> {
+        if !window.process_name.is_empty() {
+            self.processes.get(&window.process_name.to_lowercase())
+        } else {
+            self.classes.get(&window.class_name)
+        }
+    }
+
+    /// Returns the number of remembered applications
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.processes.len() + self.classes.len()
+    }
+
+    /// Returns true if no zone has been remembered
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Returns the full path of the zone memory file
+pub fn zone_memory_path() -> Result<PathBuf, ConfigError> {
+    Ok(storage::app_data_dir()?.join(ZONE_MEMORY_FILE_NAME))
+}
+
+/// Loads the zone memory from the given file
+///
+/// # Returns
+/// The stored zones, an empty memory if the file does not exist,
+/// or ConfigError if it cannot be read or parsed
+pub fn load_from(path: &Path) -> Result<ZoneMemory, ConfigError> {
+    if !path.exists() {
+        return Ok(ZoneMemory::default());
+    }
+
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Saves the zone memory to the given file, creating parent directories as needed
+pub fn save_to(memory: &ZoneMemory, path: &Path) -> Result<(), ConfigError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, serde_json::to_string_pretty(memory)?)?;
+    Ok(())
+}
+
+/// Loads the user's zone memory, starting empty on any error
+pub fn load_or_default() -> ZoneMemory {
+    match zone_memory_path().and_then(|path| load_from(&path)) {
+        Ok(memory) => memory,
+        Err(e) => {
+            eprintln!("Zones: {} - starting with no remembered zones", e);
+            ZoneMemory::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::core::Rect;
+    use windows::Win32::Foundation::HWND;
+
+    fn window(process: &str, class: &str) -> WindowInfo {
+        WindowInfo {
+            handle: HWND(0),
+            title: "Untitled".to_string(),
+            class_name: class.to_string(),
+            process_name: process.to_string(),
+            rect: Rect::new(100, 100, 800, 600),
+            normal_rect: Rect::new(100, 100, 800, 600),
+            is_resizable: true,
+            has_caption: true,
+            is_child: false,
+            is_maximized: false,
+        }
+    }
+
+    fn zone(monitor: &str, keys: (char, char)) -> RememberedZone {
+        RememberedZone {
+            monitor: MonitorId::new(monitor),
+            layout: GridConfig::new(3, 2),
+            keys: KeyRange::new(keys.0, keys.1),
+        }
+    }
+
+    #[test]
+    fn zones_are_remembered_per_process() {
+        let mut memory = ZoneMemory::default();
+        assert!(memory.remember(
+            &window("Code.exe", "Chrome_WidgetWin_1"),
+            zone("DELL", ('Q', 'A'))
+        ));
+        assert!(memory.remember(
+            &window("code.exe", "Chrome_WidgetWin_1"),
+            zone("DELL", ('W', 'S'))
+        ));
+        assert_eq!(memory.len(), 1);
+
+        // Another window of the same application gets the latest zone
+        let other = window("CODE.EXE", "Chrome_WidgetWin_1");
+        assert_eq!(memory.lookup(&other), Some(&zone("DELL", ('W', 'S'))));
+
+        // Same class, different process
+        assert_eq!(
+            memory.lookup(&window("slack.exe", "Chrome_WidgetWin_1")),
+            None
+        );
+    }
+
+    #[test]
+    fn class_is_used_when_process_is_unknown() {
+        let mut memory = ZoneMemory::default();
+        assert!(memory.remember(&window("", "Notepad"), zone("LG", ('E', 'E'))));
+        assert_eq!(
+            memory.lookup(&window("", "Notepad")),
+            Some(&zone("LG", ('E', 'E')))
+        );
+        assert_eq!(memory.lookup(&window("notepad.exe", "Notepad")), None);
+
+        assert!(!memory.remember(&window("", ""), zone("LG", ('E', 'E'))));
+    }
+
+    #[test]
+    fn memory_round_trips_through_file() {
+        let path = std::env::temp_dir()
+            .join(format!("tactile-win-zones-{}", std::process::id()))
+            .join(ZONE_MEMORY_FILE_NAME);
+        assert!(load_from(&path).unwrap().is_empty());
+
+        let mut memory = ZoneMemory::default();
+        memory.remember(
+            &window("slack.exe", "Chrome_WidgetWin_1"),
+            zone("DELL", ('E', 'D')),
+        );
+        memory.remember(&window("", "Notepad"), zone("LG", ('Q', 'Q')));
+        save_to(&memory, &path).unwrap();
+        assert_eq!(load_from(&path).unwrap(), memory);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
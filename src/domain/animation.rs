@@ -0,0 +1,368 @@
+//! Animated window transitions
+//!
+//! Plans the intermediate rectangles of a window moving from its current
+//! geometry to its target. The planner is pure: it returns a frame
+//! schedule that the platform layer plays back with `SetWindowPos`.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::core::Rect;
+
+/// Time between two animation frames (about 60 frames per second)
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Easing curve mapping animation progress to movement progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Starts slowly and accelerates (cubic)
+    EaseIn,
+    /// Starts fast and decelerates (cubic)
+    #[default]
+    EaseOut,
+    /// Accelerates, then decelerates (cubic)
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the curve to a progress value
+    ///
+    /// # Arguments
+    /// * `t` - Animation progress, clamped to 0.0..=1.0
+    ///
+    /// # Returns
+    /// Movement progress: 0.0 at the start, 1.0 at the end
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// User-configurable window animation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimationOptions {
+    /// Animate windows into place instead of moving them at once
+    pub enabled: bool,
+    /// Duration of the transition in milliseconds
+    pub duration_ms: u32,
+    /// Easing curve of the transition
+    pub easing: Easing,
+    /// Frames slower than this (in milliseconds) stop the animation, for
+    /// windows that repaint too slowly to follow
+    pub slow_frame_ms: u32,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration_ms: 150,
+            easing: Easing::default(),
+            slow_frame_ms: 50,
+        }
+    }
+}
+
+impl AnimationOptions {
+    /// Returns the transition duration
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(u64::from(self.duration_ms))
+    }
+
+    /// Returns true if a frame took too long for the window to be animated
+    pub fn is_too_slow(&self, frame_time: Duration) -> bool {
+        frame_time > Duration::from_millis(u64::from(self.slow_frame_ms))
+    }
+}
+
+/// Most windows remembered as too slow to animate
+pub const MAX_SLOW_WINDOWS: usize = 32;
+
+/// Windows that repainted too slowly to follow an animation
+///
+/// A window that cuts an animation short is placed without animation from
+/// then on. Only the most recent `MAX_SLOW_WINDOWS` are remembered, since
+/// handles of closed windows get reused.
+#[derive(Debug, Clone)]
+pub struct SlowWindows<K> {
+    keys: VecDeque<K>,
+}
+
+impl<K: PartialEq> SlowWindows<K> {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        Self {
+            keys: VecDeque::new(),
+        }
+    }
+
+    /// Remembers a window as too slow to animate
+    ///
+    /// The oldest window is forgotten once `MAX_SLOW_WINDOWS` are remembered.
+    pub fn mark(&mut self, key: K) {
+        if self.contains(&key) {
+            return;
+        }
+        if self.keys.len() == MAX_SLOW_WINDOWS {
+            self.keys.pop_front();
+        }
+        self.keys.push_back(key);
+    }
+
+    /// Returns true if the window was too slow to animate
+    pub fn contains(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns the animation options to place a window with
+    ///
+    /// # Arguments
+    /// * `key` - Window to place
+    /// * `options` - Configured animation options
+    ///
+    /// # Returns
+    /// `options`, with animation disabled if the window is too slow
+    pub fn options_for(&self, key: &K, options: AnimationOptions) -> AnimationOptions {
+        AnimationOptions {
+            enabled: options.enabled && !self.contains(key),
+            ..options
+        }
+    }
+}
+
+impl<K: PartialEq> Default for SlowWindows<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One step of an animation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimationFrame {
+    /// Time since the start of the animation at which the frame is shown
+    pub at: Duration,
+    /// Window rectangle of the frame
+    pub rect: Rect,
+}
+
+/// Interpolates between two rectangles
+///
+/// # Arguments
+/// * `start` - Rectangle at progress 0.0
+/// * `end` - Rectangle at progress 1.0
+/// * `progress` - Movement progress (not clamped, so overshooting curves work)
+pub fn interpolate(start: Rect, end: Rect, progress: f64) -> Rect {
+    let lerp = |a: i32, b: i32| a + (f64::from(b - a) * progress).round() as i32;
+    Rect::new(
+        lerp(start.x, end.x),
+        lerp(start.y, end.y),
+        lerp(start.w, end.w),
+        lerp(start.h, end.h),
+    )
+}
+
+/// Plans the frames of a transition
+///
+/// # Arguments
+/// * `start` - Current window rectangle
+/// * `end` - Target window rectangle
+/// * `duration` - Total transition time
+/// * `easing` - Easing curve
+///
+/// # Returns
+/// Frames in chronological order, one per `FRAME_INTERVAL`. Frames that
+/// would not change the rectangle are dropped, and the last frame is
+/// always exactly `end`. Empty if the window is already at the target.
+pub fn plan_frames(
+    start: Rect,
+    end: Rect,
+    duration: Duration,
+    easing: Easing,
+) -> Vec<AnimationFrame> {
+    if start == end {
+        return Vec::new();
+    }
+
+    let frame_count = duration
+        .as_millis()
+        .div_ceil(FRAME_INTERVAL.as_millis())
+        .max(1) as u32;
+
+    let mut frames: Vec<AnimationFrame> = Vec::with_capacity(frame_count as usize);
+    let mut previous = start;
+    for i in 1..=frame_count {
+        let rect = if i == frame_count {
+            end
+        } else {
+            interpolate(
+                start,
+                end,
+                easing.apply(f64::from(i) / f64::from(frame_count)),
+            )
+        };
+        if rect != previous {
+            let at = (FRAME_INTERVAL * i).min(duration);
+            frames.push(AnimationFrame { at, rect });
+            previous = rect;
+        }
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 800,
+        h: 600,
+    };
+    const END: Rect = Rect {
+        x: 960,
+        y: 0,
+        w: 960,
+        h: 1040,
+    };
+
+    #[test]
+    fn easing_curves_start_and_end_at_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0, "{:?}", easing);
+            assert_eq!(easing.apply(1.0), 1.0, "{:?}", easing);
+            assert_eq!(easing.apply(2.0), 1.0, "{:?}", easing);
+        }
+
+        assert_eq!(Easing::Linear.apply(0.5), 0.5);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    }
+
+    #[test]
+    fn interpolation_moves_every_edge() {
+        assert_eq!(interpolate(START, END, 0.0), START);
+        assert_eq!(interpolate(START, END, 1.0), END);
+        assert_eq!(interpolate(START, END, 0.5), Rect::new(480, 0, 880, 820));
+    }
+
+    #[test]
+    fn frames_follow_the_schedule_and_end_at_target() {
+        let frames = plan_frames(START, END, Duration::from_millis(160), Easing::Linear);
+        assert_eq!(frames.len(), 10);
+        assert_eq!(frames[0].at, Duration::from_millis(16));
+        assert_eq!(frames[0].rect, Rect::new(96, 0, 816, 644));
+        assert_eq!(frames[4].rect, Rect::new(480, 0, 880, 820));
+        assert_eq!(frames.last().unwrap().at, Duration::from_millis(160));
+        assert_eq!(frames.last().unwrap().rect, END);
+    }
+
+    #[test]
+    fn last_frame_is_clamped_to_duration() {
+        let frames = plan_frames(START, END, Duration::from_millis(100), Easing::EaseOut);
+        // 100 / 16 rounds up to 7 frames
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames.last().unwrap().at, Duration::from_millis(100));
+        assert!(frames.windows(2).all(|w| w[0].at < w[1].at));
+    }
+
+    #[test]
+    fn ease_out_covers_most_distance_early() {
+        let frames = plan_frames(START, END, Duration::from_millis(160), Easing::EaseOut);
+        assert!(frames[4].rect.x > 480);
+    }
+
+    #[test]
+    fn unchanged_frames_are_dropped() {
+        // A one pixel move only needs a single frame
+        let nudged = Rect::new(1, 0, 800, 600);
+        let frames = plan_frames(START, nudged, Duration::from_millis(160), Easing::Linear);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].rect, nudged);
+
+        assert!(plan_frames(START, START, Duration::from_millis(160), Easing::Linear).is_empty());
+    }
+
+    #[test]
+    fn zero_duration_jumps_to_target() {
+        let frames = plan_frames(START, END, Duration::ZERO, Easing::EaseInOut);
+        assert_eq!(
+            frames,
+            vec![AnimationFrame {
+                at: Duration::ZERO,
+                rect: END
+            }]
+        );
+    }
+
+    #[test]
+    fn slow_frames_are_detected() {
+        let options = AnimationOptions::default();
+        assert!(!options.is_too_slow(Duration::from_millis(16)));
+        assert!(options.is_too_slow(Duration::from_millis(120)));
+    }
+
+    #[test]
+    fn slow_windows_are_no_longer_animated() {
+        let options = AnimationOptions {
+            enabled: true,
+            ..AnimationOptions::default()
+        };
+        let mut slow = SlowWindows::new();
+        assert!(slow.options_for(&1, options).enabled);
+
+        slow.mark(1);
+        assert!(!slow.options_for(&1, options).enabled);
+        assert!(slow.options_for(&2, options).enabled);
+        assert_eq!(
+            slow.options_for(&1, options).duration_ms,
+            options.duration_ms
+        );
+    }
+
+    #[test]
+    fn disabled_animation_stays_disabled() {
+        let slow: SlowWindows<i32> = SlowWindows::new();
+        assert!(!slow.options_for(&1, AnimationOptions::default()).enabled);
+    }
+
+    #[test]
+    fn oldest_slow_window_is_forgotten() {
+        let mut slow = SlowWindows::new();
+        for key in 0..MAX_SLOW_WINDOWS {
+            slow.mark(key);
+        }
+        // Marking a known window again does not evict anything
+        slow.mark(0);
+        assert!(slow.contains(&0));
+
+        slow.mark(MAX_SLOW_WINDOWS);
+        assert!(!slow.contains(&0));
+        assert!(slow.contains(&1));
+        assert!(slow.contains(&MAX_SLOW_WINDOWS));
+    }
+}
@@ -3,11 +3,13 @@
 //! This module defines pure domain types that work exclusively with
 //! real pixels and have no knowledge of Win32 or DPI concepts.
 
+use serde::{Deserialize, Serialize};
+
 /// Rectangle in real pixel coordinates
 ///
 /// This is the fundamental building block for all geometric calculations.
 /// All coordinates are in real pixels, already DPI-normalized by the platform layer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Rect {
     pub x: i32,
     pub y: i32,
@@ -64,6 +66,23 @@ impl Rect {
     pub fn area(&self) -> i32 {
         self.w * self.h
     }
+
+    /// Returns the rectangle shrunk by `amount` pixels on every side
+    ///
+    /// The size never drops below 1x1; the result stays centered on the original.
+    pub fn inset(&self, amount: i32) -> Rect {
+        let w = (self.w - 2 * amount).max(1);
+        let h = (self.h - 2 * amount).max(1);
+        Rect::new(self.x + (self.w - w) / 2, self.y + (self.h - h) / 2, w, h)
+    }
+
+    /// Returns true if this rectangle fully contains the other one
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
 }
 
 #[cfg(test)]
@@ -110,4 +129,22 @@ mod tests {
         let union = rect1.union(&rect2);
         assert_eq!(union, Rect::new(0, 0, 30, 30));
     }
+
+    #[test]
+    fn rect_inset() {
+        let rect = Rect::new(0, 0, 100, 50);
+        assert_eq!(rect.inset(10), Rect::new(10, 10, 80, 30));
+        assert_eq!(rect.inset(0), rect);
+
+        // Too large an inset collapses toward the center
+        assert_eq!(rect.inset(40), Rect::new(40, 24, 20, 1));
+    }
+
+    #[test]
+    fn rect_contains_rect() {
+        let outer = Rect::new(0, 0, 100, 100);
+        assert!(outer.contains_rect(&Rect::new(10, 10, 50, 50)));
+        assert!(outer.contains_rect(&outer));
+        assert!(!outer.contains_rect(&Rect::new(60, 60, 50, 50)));
+    }
 }
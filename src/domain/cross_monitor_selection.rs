@@ -0,0 +1,284 @@
+//! Selections spanning two monitors
+//!
+//! The first corner of a selection may be picked on one monitor and the second
+//! on another. The resulting window rectangle is the bounding box of both
+//! cells, constrained to the area the two monitors actually cover according
+//! to a `CrossMonitorPolicy`.
+//!
+//! ## Policies
+//! - **Refuse**: reject monitors with different DPI scale or misaligned edges
+//! - **ClipToLargest**: shrink to the largest rectangle fully covered by the work areas
+//! - **Stretch**: keep the full bounding box, even where it leaves the work areas
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::core::Rect;
+use crate::domain::keyboard::GridCoords;
+
+/// Maximum DPI scale difference still considered "the same DPI"
+const DPI_SCALE_TOLERANCE: f32 = 0.01;
+
+/// How to handle selections across monitors that don't line up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossMonitorPolicy {
+    /// Reject selections across monitors with different DPI or misaligned edges
+    Refuse,
+    /// Shrink to the largest rectangle fully covered by the two work areas
+    #[default]
+    ClipToLargest,
+    /// Keep the full bounding box, even if part of it is off-screen
+    Stretch,
+}
+
+/// Errors that can occur when combining a cross-monitor selection
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrossMonitorError {
+    /// The monitors use different DPI scale factors (Refuse policy)
+    DpiMismatch { start_scale: f32, end_scale: f32 },
+    /// The bounding box is not fully covered by the work areas (Refuse policy)
+    Misaligned { bounding_box: Rect },
+    /// No rectangle inside the work areas spans both cells (ClipToLargest policy)
+    NoCoveredArea { bounding_box: Rect },
+}
+
+/// A grid cell picked on a specific monitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorCell {
+    /// Index of the monitor the cell belongs to
+    pub monitor_index: usize,
+    /// Cell coordinates within that monitor's grid
+    pub coords: GridCoords,
+}
+
+impl MonitorCell {
+    /// Creates a new monitor cell
+    pub fn new(monitor_index: usize, coords: GridCoords) -> Self {
+        Self {
+            monitor_index,
+            coords,
+        }
+    }
+}
+
+/// Screen geometry of one side of a cross-monitor selection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelectionEnd {
+    /// Screen rectangle of the picked cell
+    pub cell_rect: Rect,
+    /// Work area of the monitor the cell belongs to
+    pub work_area: Rect,
+    /// DPI scale factor of that monitor
+    pub dpi_scale: f32,
+}
+
+/// Combines two cells on (possibly) different monitors into one rectangle
+///
+/// # Arguments
+/// * `start` - First picked cell and its monitor geometry
+/// * `end` - Second picked cell and its monitor geometry
+/// * `policy` - How to handle DPI differences and misaligned monitors
+///
+/// # Returns
+/// The target rectangle in screen coordinates, or CrossMonitorError
+///
+/// # Example
+/// ```ignore
+/// // Two 1920x1080 monitors side by side, left half of each
+/// let rect = combine_selection(left_cell, right_cell, CrossMonitorPolicy::Refuse)?;
+/// ```
+pub fn combine_selection(
+    start: SelectionEnd,
+    end: SelectionEnd,
+    policy: CrossMonitorPolicy,
+) -> Result<Rect, CrossMonitorError> {
+    let bounding_box = start.cell_rect.union(&end.cell_rect);
+
+    // A selection within a single work area needs no policy decisions
+    if start.work_area == end.work_area {
+        return Ok(bounding_box);
+    }
+
+    match policy {
+        CrossMonitorPolicy::Refuse => {
+            if (start.dpi_scale - end.dpi_scale).abs() > DPI_SCALE_TOLERANCE {
+                return Err(CrossMonitorError::DpiMismatch {
+                    start_scale: start.dpi_scale,
+                    end_scale: end.dpi_scale,
+                });
+            }
+            if !is_covered(bounding_box, start.work_area, end.work_area) {
+                return Err(CrossMonitorError::Misaligned { bounding_box });
+            }
+            Ok(bounding_box)
+        }
+        CrossMonitorPolicy::ClipToLargest => {
+            largest_covered_rect(bounding_box, start.work_area, end.work_area)
+                .ok_or(CrossMonitorError::NoCoveredArea { bounding_box })
+        }
+        CrossMonitorPolicy::Stretch => Ok(bounding_box),
+    }
+}
+
+/// Returns true if every pixel of `rect` lies within `a` or `b`
+fn is_covered(rect: Rect, a: Rect, b: Rect) -> bool {
+    let in_a = rect.intersection(&a).map_or(0, |r| r.area() as i64);
+    let in_b = rect.intersection(&b).map_or(0, |r| r.area() as i64);
+    let in_both = rect
+        .intersection(&a)
+        .and_then(|r| r.intersection(&b))
+        .map_or(0, |r| r.area() as i64);
+
+    in_a + in_b - in_both == rect.area() as i64
+}
+
+/// Finds the largest rectangle inside `bounding_box` that `a` and `b` fully cover
+///
+/// For side-by-side monitors the full width is kept and the height is limited
+/// to the rows both work areas share; for stacked monitors the reverse.
+fn largest_covered_rect(bounding_box: Rect, a: Rect, b: Rect) -> Option<Rect> {
+    // Full width, height limited to the vertical overlap of both work areas
+    let horizontal_band = Rect::new(
+        bounding_box.x,
+        a.y.max(b.y),
+        bounding_box.w,
+        a.bottom().min(b.bottom()) - a.y.max(b.y),
+    );
+    // Full height, width limited to the horizontal overlap of both work areas
+    let vertical_band = Rect::new(
+        a.x.max(b.x),
+        bounding_box.y,
+        a.right().min(b.right()) - a.x.max(b.x),
+        bounding_box.h,
+    );
+
+    [horizontal_band, vertical_band]
+        .into_iter()
+        .filter_map(|band| bounding_box.intersection(&band))
+        .filter(|candidate| is_covered(*candidate, a, b))
+        .max_by_key(|candidate| candidate.area())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn end(cell_rect: Rect, work_area: Rect, dpi_scale: f32) -> SelectionEnd {
+        SelectionEnd {
+            cell_rect,
+            work_area,
+            dpi_scale,
+        }
+    }
+
+    // Two aligned 1920x1080 monitors side by side
+    const LEFT: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 1920,
+        h: 1080,
+    };
+    const RIGHT: Rect = Rect {
+        x: 1920,
+        y: 0,
+        w: 1920,
+        h: 1080,
+    };
+
+    #[test]
+    fn same_monitor_returns_bounding_box() {
+        let start = end(Rect::new(0, 0, 640, 540), LEFT, 1.0);
+        let finish = end(Rect::new(640, 540, 640, 540), LEFT, 1.0);
+
+        let rect = combine_selection(start, finish, CrossMonitorPolicy::Refuse).unwrap();
+        assert_eq!(rect, Rect::new(0, 0, 1280, 1080));
+    }
+
+    #[test]
+    fn aligned_monitors_span_both_cells() {
+        // Right column of the left monitor to left column of the right monitor
+        let start = end(Rect::new(1280, 0, 640, 540), LEFT, 1.0);
+        let finish = end(Rect::new(1920, 540, 640, 540), RIGHT, 1.0);
+
+        for policy in [
+            CrossMonitorPolicy::Refuse,
+            CrossMonitorPolicy::ClipToLargest,
+            CrossMonitorPolicy::Stretch,
+        ] {
+            let rect = combine_selection(start, finish, policy).unwrap();
+            assert_eq!(rect, Rect::new(1280, 0, 1280, 1080));
+        }
+    }
+
+    #[test]
+    fn refuse_rejects_dpi_mismatch() {
+        let start = end(Rect::new(1280, 0, 640, 540), LEFT, 1.0);
+        let finish = end(Rect::new(1920, 0, 640, 540), RIGHT, 1.5);
+
+        let result = combine_selection(start, finish, CrossMonitorPolicy::Refuse);
+        assert!(matches!(result, Err(CrossMonitorError::DpiMismatch { .. })));
+
+        // Other policies accept different DPI
+        assert!(combine_selection(start, finish, CrossMonitorPolicy::Stretch).is_ok());
+    }
+
+    #[test]
+    fn misaligned_monitors_by_policy() {
+        // Right monitor is 1440 tall and starts 200px higher than the left one
+        let tall_right = Rect::new(1920, -200, 2560, 1440);
+        let start = end(Rect::new(1280, 0, 640, 1080), LEFT, 1.0);
+        let finish = end(Rect::new(1920, -200, 853, 1440), tall_right, 1.0);
+
+        let bounding_box = Rect::new(1280, -200, 1493, 1440);
+
+        assert_eq!(
+            combine_selection(start, finish, CrossMonitorPolicy::Refuse),
+            Err(CrossMonitorError::Misaligned { bounding_box })
+        );
+        assert_eq!(
+            combine_selection(start, finish, CrossMonitorPolicy::Stretch),
+            Ok(bounding_box)
+        );
+        // Clipped to the rows both monitors share
+        assert_eq!(
+            combine_selection(start, finish, CrossMonitorPolicy::ClipToLargest),
+            Ok(Rect::new(1280, 0, 1493, 1080))
+        );
+    }
+
+    #[test]
+    fn stacked_monitors_clip_horizontally() {
+        let top = Rect::new(0, 0, 1920, 1080);
+        let bottom = Rect::new(320, 1080, 1280, 1024);
+        let start = end(Rect::new(0, 540, 960, 540), top, 1.0);
+        let finish = end(Rect::new(320, 1080, 640, 512), bottom, 1.0);
+
+        assert_eq!(
+            combine_selection(start, finish, CrossMonitorPolicy::ClipToLargest),
+            Ok(Rect::new(320, 540, 640, 1052))
+        );
+    }
+
+    #[test]
+    fn diagonal_monitors_have_no_covered_area() {
+        let top_left = Rect::new(0, 0, 1920, 1080);
+        let bottom_right = Rect::new(1920, 1080, 1920, 1080);
+        let start = end(Rect::new(0, 0, 960, 540), top_left, 1.0);
+        let finish = end(Rect::new(2880, 1620, 960, 540), bottom_right, 1.0);
+
+        assert!(matches!(
+            combine_selection(start, finish, CrossMonitorPolicy::ClipToLargest),
+            Err(CrossMonitorError::NoCoveredArea { .. })
+        ));
+    }
+
+    #[test]
+    fn policy_deserializes_from_snake_case() {
+        let policy: CrossMonitorPolicy = serde_json::from_str("\"clip_to_largest\"").unwrap();
+        assert_eq!(policy, CrossMonitorPolicy::ClipToLargest);
+        assert_eq!(
+            CrossMonitorPolicy::default(),
+            CrossMonitorPolicy::ClipToLargest
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Window frame compensation
+//!
+//! On Windows 10/11 the rectangle passed to SetWindowPos includes invisible
+//! resize borders and drop shadows around the visible frame. Positioning a
+//! window at a grid rectangle therefore leaves visible gaps between neighbours.
+//! These types compute the invisible margins and the window rectangle whose
+//! visible frame matches a target rectangle.
+
+use crate::domain::core::Rect;
+use crate::domain::placement::SizeConstraints;
+
+/// Invisible space between a window's rectangle and its visible frame
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameMargins {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl FrameMargins {
+    /// Creates frame margins
+    pub fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Measures the margins between a window rectangle and its visible frame
+    ///
+    /// Negative margins (a frame larger than the window, which only happens
+    /// with inconsistent measurements) are treated as zero.
+    ///
+    /// # Arguments
+    /// * `window_rect` - Rectangle reported by GetWindowRect
+    /// * `visible_frame` - Visible frame bounds (DWM extended frame bounds)
+    pub fn between(window_rect: Rect, visible_frame: Rect) -> Self {
+        Self::new(
+            (visible_frame.x - window_rect.x).max(0),
+            (visible_frame.y - window_rect.y).max(0),
+            (window_rect.right() - visible_frame.right()).max(0),
+            (window_rect.bottom() - visible_frame.bottom()).max(0),
+        )
+    }
+
+    /// Returns the window rectangle whose visible frame covers `target`
+    pub fn expand(&self, target: Rect) -> Rect {
+        Rect::new(
+            target.x - self.left,
+            target.y - self.top,
+            target.w + self.left + self.right,
+            target.h + self.top + self.bottom,
+        )
+    }
+
+    /// Returns the visible frame of a window rectangle (inverse of `expand`)
+    pub fn shrink(&self, window_rect: Rect) -> Rect {
+        Rect::new(
+            window_rect.x + self.left,
+            window_rect.y + self.top,
+            window_rect.w - self.left - self.right,
+            window_rect.h - self.top - self.bottom,
+        )
+    }
+
+    /// Converts window size constraints into constraints on the visible frame
+    pub fn visible_constraints(&self, constraints: SizeConstraints) -> SizeConstraints {
+        let horizontal = self.left + self.right;
+        let vertical = self.top + self.bottom;
+
+        SizeConstraints {
+            min_width: (constraints.min_width - horizontal).max(0),
+            min_height: (constraints.min_height - vertical).max(0),
+            max_width: constraints.max_width.saturating_sub(horizontal),
+            max_height: constraints.max_height.saturating_sub(vertical),
+        }
+    }
+
+    /// Returns true if the window has no invisible borders
+    #[cfg(test)]
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_windows_11_resize_borders() {
+        // Typical Windows 10/11 window: 7px invisible borders left, right and bottom
+        let window_rect = Rect::new(93, 100, 814, 607);
+        let visible_frame = Rect::new(100, 100, 800, 600);
+
+        assert_eq!(
+            FrameMargins::between(window_rect, visible_frame),
+            FrameMargins::new(7, 0, 7, 7)
+        );
+    }
+
+    #[test]
+    fn expanded_rect_has_visible_frame_on_target() {
+        let margins = FrameMargins::new(7, 0, 7, 7);
+        let target = Rect::new(0, 0, 960, 540);
+
+        let window_rect = margins.expand(target);
+        assert_eq!(window_rect, Rect::new(-7, 0, 974, 547));
+        assert_eq!(FrameMargins::between(window_rect, target), margins);
+        assert_eq!(margins.shrink(window_rect), target);
+    }
+
+    #[test]
+    fn borderless_windows_need_no_compensation() {
+        let rect = Rect::new(0, 0, 1920, 1080);
+        let margins = FrameMargins::between(rect, rect);
+
+        assert!(margins.is_zero());
+        assert_eq!(margins.expand(rect), rect);
+    }
+
+    #[test]
+    fn constraints_are_converted_to_visible_frame() {
+        let margins = FrameMargins::new(7, 0, 7, 7);
+        let constraints = SizeConstraints {
+            min_width: 514,
+            min_height: 407,
+            max_width: i32::MAX,
+            max_height: 1087,
+        };
+
+        let visible = margins.visible_constraints(constraints);
+        assert_eq!((visible.min_width, visible.min_height), (500, 400));
+        assert_eq!(visible.max_height, 1080);
+        assert_eq!(visible.max_width, i32::MAX - 14);
+    }
+
+    #[test]
+    fn inconsistent_measurements_are_clamped() {
+        let window_rect = Rect::new(100, 100, 800, 600);
+        let visible_frame = Rect::new(95, 100, 810, 600);
+
+        assert!(FrameMargins::between(window_rect, visible_frame).is_zero());
+    }
+}
@@ -28,6 +28,17 @@ pub enum GridError {
     InvalidCellSize { width: u32, height: u32 },
 }
 
+/// Outcome of fitting a requested grid onto a screen area
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridFit {
+    /// The requested grid fits as-is
+    Exact(Grid),
+    /// The requested grid did not fit and a smaller one was used instead
+    Reduced(Grid),
+    /// Not even a 1x1 grid fits; carries the error for the requested grid
+    Unusable(GridError),
+}
+
 /// Represents a logical grid that can be overlaid on a screen area
 ///
 /// The grid divides a rectangular screen area into a grid of cells.
@@ -54,6 +65,8 @@ impl Grid {
     pub const MIN_CELL_WIDTH: u32 = 480;
     /// Minimum cell height in pixels (from architecture requirements)
     pub const MIN_CELL_HEIGHT: u32 = 360;
+    /// Monitors with a shorter work area are excluded from grid positioning
+    pub const MIN_MONITOR_HEIGHT: i32 = 600;
 
     /// Creates a new grid for the specified screen area
     ///
@@ -117,6 +130,31 @@ impl Grid {
         })
     }
 
+    /// Creates the largest grid that fits the screen area, up to the requested size
+    ///
+    /// Tries the requested dimensions first, then progressively smaller
+    /// layouts (see `fallback_dimensions`) down to 1x1.
+    ///
+    /// # Arguments
+    /// * `rows` - Requested number of grid rows
+    /// * `cols` - Requested number of grid columns
+    /// * `screen_area` - Screen rectangle the grid will cover
+    ///
+    /// # Returns
+    /// GridFit describing whether the requested grid, a smaller one, or none fits
+    pub fn fit(rows: u32, cols: u32, screen_area: Rect) -> GridFit {
+        let requested_error = match Self::new(rows, cols, screen_area) {
+            Ok(grid) => return GridFit::Exact(grid),
+            Err(e) => e,
+        };
+
+        fallback_dimensions(rows, cols)
+            .into_iter()
+            .skip(1) // The requested dimensions were already tried
+            .find_map(|(r, c)| Self::new(r, c, screen_area).ok())
+            .map_or(GridFit::Unusable(requested_error), GridFit::Reduced)
+    }
+
     /// Returns the grid dimensions as (rows, cols)
     pub fn dimensions(&self) -> (u32, u32) {
         (self.rows, self.cols)
@@ -253,6 +291,20 @@ impl Grid {
         coords.row < self.rows && coords.col < self.cols
     }
 
+    /// Checks if a selection between two cells covers every cell of the grid
+    ///
+    /// # Arguments
+    /// * `start` - One corner of the selection
+    /// * `end` - Opposite corner of the selection
+    ///
+    /// # Returns
+    /// true if the corners are opposite corners of the grid (in any order)
+    pub fn spans_all_cells(&self, start: GridCoords, end: GridCoords) -> bool {
+        let rows = (start.row.min(end.row), start.row.max(end.row));
+        let cols = (start.col.min(end.col), start.col.max(end.col));
+        rows == (0, self.rows - 1) && cols == (0, self.cols - 1)
+    }
+
     /// Checks if the given keyboard key is valid for this grid
     ///
     /// # Arguments
@@ -390,6 +442,23 @@ impl Grid {
     }
 }
 
+/// Lists candidate grid dimensions from the requested size down to 1x1
+///
+/// Candidates never exceed the requested rows or columns and are ordered by
+/// cell count (largest first). Ties prefer more columns, which suits the
+/// common landscape monitor.
+///
+/// # Returns
+/// (rows, cols) pairs, starting with the requested dimensions
+pub fn fallback_dimensions(rows: u32, cols: u32) -> Vec<(u32, u32)> {
+    let mut candidates: Vec<(u32, u32)> = (1..=rows)
+        .flat_map(|r| (1..=cols).map(move |c| (r, c)))
+        .collect();
+
+    candidates.sort_by_key(|&(r, c)| (std::cmp::Reverse(r * c), std::cmp::Reverse(c)));
+    candidates
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -595,4 +664,58 @@ mod tests {
         let rect = grid.keys_to_rect('Q', 'S').unwrap();
         assert_eq!(rect, Rect::new(1920, 0, 1920, 720));
     }
+
+    #[test]
+    fn fallback_dimensions_order() {
+        let candidates = fallback_dimensions(2, 3);
+        assert_eq!(
+            candidates,
+            vec![(2, 3), (2, 2), (1, 3), (1, 2), (2, 1), (1, 1)]
+        );
+
+        assert_eq!(fallback_dimensions(1, 1), vec![(1, 1)]);
+    }
+
+    #[test]
+    fn fit_uses_requested_grid_when_possible() {
+        let fit = Grid::fit(2, 3, Rect::new(0, 0, 1920, 1040));
+        assert!(matches!(fit, GridFit::Exact(ref grid) if grid.dimensions() == (2, 3)));
+    }
+
+    #[test]
+    fn fit_falls_back_for_small_portrait_screen() {
+        // Portrait tablet: too narrow for multiple columns, tall enough for 2 rows
+        let fit = Grid::fit(2, 3, Rect::new(0, 0, 800, 1240));
+        match fit {
+            GridFit::Reduced(grid) => assert_eq!(grid.dimensions(), (2, 1)),
+            other => panic!("Expected reduced grid, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn fit_falls_back_to_single_cell() {
+        let fit = Grid::fit(2, 3, Rect::new(0, 0, 800, 600));
+        match fit {
+            GridFit::Reduced(grid) => assert_eq!(grid.dimensions(), (1, 1)),
+            other => panic!("Expected reduced grid, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn fit_reports_unusable_screen() {
+        let fit = Grid::fit(2, 3, Rect::new(0, 0, 400, 300));
+        assert!(matches!(
+            fit,
+            GridFit::Unusable(GridError::ScreenTooSmall { .. })
+        ));
+    }
+
+    #[test]
+    fn full_selection_spans_all_cells() {
+        let grid = Grid::new(2, 3, Rect::new(0, 0, 1920, 1080)).unwrap();
+
+        assert!(grid.spans_all_cells(GridCoords::new(0, 0), GridCoords::new(1, 2)));
+        assert!(grid.spans_all_cells(GridCoords::new(1, 0), GridCoords::new(0, 2)));
+        assert!(!grid.spans_all_cells(GridCoords::new(0, 0), GridCoords::new(1, 1)));
+    }
 }
@@ -0,0 +1,285 @@
+//! Per-window placement undo/redo history
+//!
+//! Every placement records the window geometry before and after it. Undo
+//! returns the geometry to go back to, redo the geometry to re-apply. Like a
+//! text editor, recording a new placement after an undo discards the redo
+//! branch. History is bounded both per window and in the number of windows.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::domain::core::Rect;
+
+/// Maximum number of placements remembered per window
+pub const MAX_ENTRIES_PER_WINDOW: usize = 20;
+/// Maximum number of windows with remembered placements
+pub const MAX_WINDOWS: usize = 64;
+
+/// Window geometry at one point in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowSnapshot {
+    /// Window rectangle in screen coordinates
+    pub rect: Rect,
+    /// Whether the window was maximized
+    pub maximized: bool,
+    /// Restored bounds of the window, which differ from `rect` while maximized
+    pub normal_rect: Rect,
+}
+
+impl WindowSnapshot {
+    /// Creates a window snapshot
+    ///
+    /// # Arguments
+    /// * `rect` - Window rectangle in screen coordinates
+    /// * `maximized` - Whether the window was maximized
+    /// * `normal_rect` - Restored bounds in screen coordinates
+    pub fn new(rect: Rect, maximized: bool, normal_rect: Rect) -> Self {
+        Self {
+            rect,
+            maximized,
+            normal_rect,
+        }
+    }
+}
+
+/// A single placement: geometry before and after
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlacementEntry {
+    pub before: WindowSnapshot,
+    pub after: WindowSnapshot,
+}
+
+/// Undo and redo stacks for one window
+#[derive(Debug, Clone, Default)]
+struct WindowHistory {
+    undo: Vec<PlacementEntry>,
+    redo: Vec<PlacementEntry>,
+    /// Logical time of the last change, used to evict the least recently used window
+    last_used: u64,
+}
+
+/// Bounded placement history keyed by window identity
+///
+/// # Example
+/// ```ignore
+/// let mut history = PlacementHistory::new();
+/// history.record(hwnd, before, after);
+/// let previous = history.undo(&hwnd); // Some(before)
+/// let again = history.redo(&hwnd);    // Some(after)
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlacementHistory<K> {
+    windows: HashMap<K, WindowHistory>,
+    clock: u64,
+}
+
+impl<K: Eq + Hash + Clone> Default for PlacementHistory<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Clone> PlacementHistory<K> {
+    /// Creates an empty history
+    pub fn new() -> Self {
+        Self {
+            windows: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Records a placement of a window
+    ///
+    /// Discards any redo entries of that window and drops the oldest
+    /// entries beyond `MAX_ENTRIES_PER_WINDOW`.
+    pub fn record(&mut self, window: K, before: WindowSnapshot, after: WindowSnapshot) {
+        if before == after {
+            return;
+        }
+
+        self.clock += 1;
+        if !self.windows.contains_key(&window) && self.windows.len() >= MAX_WINDOWS {
+            self.evict_least_recently_used();
+        }
+
+        let history = self.windows.entry(window).or_default();
+        history.redo.clear();
+        history.undo.push(PlacementEntry { before, after });
+        if history.undo.len() > MAX_ENTRIES_PER_WINDOW {
+            history.undo.remove(0);
+        }
+        history.last_used = self.clock;
+    }
+
+    /// Undoes the most recent placement of a window
+    ///
+    /// # Returns
+    /// The geometry to restore, or None if there is nothing to undo
+    pub fn undo(&mut self, window: &K) -> Option<WindowSnapshot> {
+        self.clock += 1;
+        let history = self.windows.get_mut(window)?;
+        let entry = history.undo.pop()?;
+        history.redo.push(entry);
+        history.last_used = self.clock;
+        Some(entry.before)
+    }
+
+    /// Re-applies the most recently undone placement of a window
+    ///
+    /// # Returns
+    /// The geometry to apply, or None if there is nothing to redo
+    pub fn redo(&mut self, window: &K) -> Option<WindowSnapshot> {
+        self.clock += 1;
+        let history = self.windows.get_mut(window)?;
+        let entry = history.redo.pop()?;
+        history.undo.push(entry);
+        history.last_used = self.clock;
+        Some(entry.after)
+    }
+
+    /// Returns true if the window has a placement to undo
+    #[cfg(test)]
+    pub fn can_undo(&self, window: &K) -> bool {
+        self.windows.get(window).is_some_and(|h| !h.undo.is_empty())
+    }
+
+    /// Returns true if the window has an undone placement to redo
+    #[cfg(test)]
+    pub fn can_redo(&self, window: &K) -> bool {
+        self.windows.get(window).is_some_and(|h| !h.redo.is_empty())
+    }
+
+    /// Forgets all placements of a window (e.g. after it was closed)
+    pub fn forget(&mut self, window: &K) {
+        self.windows.remove(window);
+    }
+
+    /// Returns the number of windows with remembered placements
+    #[cfg(test)]
+    pub fn window_count(&self) -> usize {
+        self.windows.len()
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        if let Some(oldest) = self
+            .windows
+            .iter()
+            .min_by_key(|(_, history)| history.last_used)
+            .map(|(window, _)| window.clone())
+        {
+            self.windows.remove(&oldest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(x: i32) -> WindowSnapshot {
+        WindowSnapshot::new(Rect::new(x, 0, 800, 600), false, Rect::new(x, 0, 800, 600))
+    }
+
+    #[test]
+    fn undo_and_redo_single_placement() {
+        let mut history = PlacementHistory::new();
+        history.record(1, snapshot(0), snapshot(100));
+
+        assert_eq!(history.undo(&1), Some(snapshot(0)));
+        assert_eq!(history.undo(&1), None);
+        assert_eq!(history.redo(&1), Some(snapshot(100)));
+        assert_eq!(history.redo(&1), None);
+    }
+
+    #[test]
+    fn undo_walks_back_through_placements() {
+        let mut history = PlacementHistory::new();
+        history.record(1, snapshot(0), snapshot(100));
+        history.record(1, snapshot(100), snapshot(200));
+
+        assert_eq!(history.undo(&1), Some(snapshot(100)));
+        assert_eq!(history.undo(&1), Some(snapshot(0)));
+        assert!(!history.can_undo(&1));
+        assert!(history.can_redo(&1));
+    }
+
+    #[test]
+    fn new_placement_after_undo_discards_redo_branch() {
+        let mut history = PlacementHistory::new();
+        history.record(1, snapshot(0), snapshot(100));
+        history.record(1, snapshot(100), snapshot(200));
+        history.undo(&1);
+
+        // Branch: place somewhere else instead of redoing
+        history.record(1, snapshot(100), snapshot(300));
+
+        assert!(!history.can_redo(&1));
+        assert_eq!(history.redo(&1), None);
+        assert_eq!(history.undo(&1), Some(snapshot(100)));
+        assert_eq!(history.undo(&1), Some(snapshot(0)));
+    }
+
+    #[test]
+    fn windows_have_independent_histories() {
+        let mut history = PlacementHistory::new();
+        history.record(1, snapshot(0), snapshot(100));
+        history.record(2, snapshot(500), snapshot(600));
+
+        assert_eq!(history.undo(&1), Some(snapshot(0)));
+        assert!(history.can_undo(&2));
+        assert_eq!(history.undo(&3), None);
+    }
+
+    #[test]
+    fn unchanged_geometry_is_not_recorded() {
+        let mut history = PlacementHistory::new();
+        history.record(1, snapshot(0), snapshot(0));
+        assert!(!history.can_undo(&1));
+    }
+
+    #[test]
+    fn maximized_state_is_part_of_snapshot() {
+        let mut history = PlacementHistory::new();
+        let maximized = WindowSnapshot::new(
+            Rect::new(0, 0, 1920, 1080),
+            true,
+            Rect::new(200, 100, 800, 600),
+        );
+        history.record(1, maximized, snapshot(0));
+
+        let restored = history.undo(&1).unwrap();
+        assert_eq!(restored, maximized);
+        assert_eq!(restored.normal_rect, Rect::new(200, 100, 800, 600));
+    }
+
+    #[test]
+    fn history_is_bounded_per_window() {
+        let mut history = PlacementHistory::new();
+        for i in 0..(MAX_ENTRIES_PER_WINDOW as i32 + 5) {
+            history.record(1, snapshot(i), snapshot(i + 1));
+        }
+
+        let mut undone = 0;
+        while history.undo(&1).is_some() {
+            undone += 1;
+        }
+        assert_eq!(undone, MAX_ENTRIES_PER_WINDOW);
+    }
+
+    #[test]
+    fn least_recently_used_window_is_evicted() {
+        let mut history = PlacementHistory::new();
+        for window in 0..MAX_WINDOWS {
+            history.record(window, snapshot(0), snapshot(100));
+        }
+        // Touch window 0 so window 1 becomes the oldest
+        history.record(0, snapshot(100), snapshot(200));
+
+        history.record(MAX_WINDOWS, snapshot(0), snapshot(100));
+
+        assert_eq!(history.window_count(), MAX_WINDOWS);
+        assert!(history.can_undo(&0));
+        assert!(!history.can_undo(&1));
+        assert!(history.can_undo(&MAX_WINDOWS));
+    }
+}
@@ -0,0 +1,283 @@
+//! Master/stack dynamic tiling
+//!
+//! A dwm-style layout: one master window takes a fraction of the work area
+//! on the left and the remaining windows share the stack column on the
+//! right. The window order is kept per monitor so promote and demote
+//! commands can rearrange windows between re-layouts.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::core::Rect;
+
+/// Smallest fraction of the work area the master window can take
+pub const MIN_MASTER_RATIO: f32 = 0.2;
+/// Largest fraction of the work area the master window can take
+pub const MAX_MASTER_RATIO: f32 = 0.8;
+/// Ratio change applied by one resize-master command
+pub const MASTER_RATIO_STEP: f32 = 0.05;
+
+/// User-configurable master/stack settings
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MasterStackConfig {
+    /// Initial fraction of the work area width taken by the master window
+    pub ratio: f32,
+    /// Pixels between windows and around the work area edges
+    pub gaps: i32,
+}
+
+impl Default for MasterStackConfig {
+    fn default() -> Self {
+        Self {
+            ratio: 0.55,
+            gaps: 0,
+        }
+    }
+}
+
+/// Computes the master/stack layout of a work area
+///
+/// # Arguments
+/// * `work_area` - Area to lay out, in screen coordinates
+/// * `n_windows` - Number of windows (the first one is the master)
+/// * `ratio` - Fraction of the width taken by the master, clamped to the allowed range
+/// * `gaps` - Pixels between windows and around the edges (negative values are treated as 0)
+///
+/// # Returns
+/// One rectangle per window: master first, then the stack from top to bottom
+pub fn master_stack_layout(work_area: Rect, n_windows: usize, ratio: f32, gaps: i32) -> Vec<Rect> {
+    if n_windows == 0 {
+        return Vec::new();
+    }
+
+    let gaps = gaps.max(0);
+    let area = work_area.inset(gaps);
+    if n_windows == 1 {
+        return vec![area];
+    }
+
+    let ratio = ratio.clamp(MIN_MASTER_RATIO, MAX_MASTER_RATIO);
+    let available_width = (area.w - gaps).max(2);
+    let master_width =
+        ((available_width as f32 * ratio).round() as i32).clamp(1, available_width - 1);
+    let stack_width = available_width - master_width;
+    let stack_x = area.x + master_width + gaps;
+
+    let mut rects = vec![Rect::new(area.x, area.y, master_width, area.h)];
+
+    // Distribute the stack height, giving leftover pixels to the top windows
+    let stack_count = (n_windows - 1) as i32;
+    let available_height = (area.h - gaps * (stack_count - 1)).max(stack_count);
+    let base_height = available_height / stack_count;
+    let extra = available_height % stack_count;
+
+    let mut y = area.y;
+    for i in 0..stack_count {
+        let height = base_height + i32::from(i < extra);
+        rects.push(Rect::new(stack_x, y, stack_width, height));
+        y += height + gaps;
+    }
+
+    rects
+}
+
+/// Window order and master ratio of one monitor in master/stack mode
+///
+/// # Example
+/// ```ignore
+/// let mut stack = MasterStack::new(0.55);
+/// stack.sync(&[editor, terminal, browser]);
+/// stack.promote(&browser); // browser becomes the master
+/// let rects = master_stack_layout(work_area, stack.windows().len(), stack.ratio(), 8);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasterStack<K> {
+    windows: Vec<K>,
+    ratio: f32,
+}
+
+impl<K: PartialEq + Clone> MasterStack<K> {
+    /// Creates an empty layout with the given master ratio
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            windows: Vec::new(),
+            ratio: ratio.clamp(MIN_MASTER_RATIO, MAX_MASTER_RATIO),
+        }
+    }
+
+    /// Returns the windows in layout order (master first)
+    pub fn windows(&self) -> &[K] {
+        &self.windows
+    }
+
+    /// Returns true if the window is part of the layout
+    pub fn contains(&self, window: &K) -> bool {
+        self.position(window).is_some()
+    }
+
+    /// Returns the master ratio
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Updates the window list to the windows currently on the monitor
+    ///
+    /// Known windows keep their order; closed windows are dropped and new
+    /// windows are appended to the stack in the given order.
+    pub fn sync(&mut self, current: &[K]) {
+        self.windows.retain(|w| current.contains(w));
+        for window in current {
+            if !self.windows.contains(window) {
+                self.windows.push(window.clone());
+            }
+        }
+    }
+
+    /// Makes a window the master
+    ///
+    /// Promoting the master swaps it with the first stack window, so the
+    /// command toggles between the two most important windows.
+    ///
+    /// # Returns
+    /// false if the window is not part of the layout
+    pub fn promote(&mut self, window: &K) -> bool {
+        let Some(index) = self.position(window) else {
+            return false;
+        };
+
+        if index == 0 {
+            if self.windows.len() > 1 {
+                self.windows.swap(0, 1);
+            }
+        } else {
+            let promoted = self.windows.remove(index);
+            self.windows.insert(0, promoted);
+        }
+        true
+    }
+
+    /// Moves a window one position down the stack (the master becomes the first stack window)
+    ///
+    /// # Returns
+    /// false if the window is not part of the layout
+    pub fn demote(&mut self, window: &K) -> bool {
+        let Some(index) = self.position(window) else {
+            return false;
+        };
+
+        if index + 1 < self.windows.len() {
+            self.windows.swap(index, index + 1);
+        }
+        true
+    }
+
+    /// Grows (positive delta) or shrinks (negative delta) the master area
+    pub fn adjust_ratio(&mut self, delta: f32) {
+        self.ratio = (self.ratio + delta).clamp(MIN_MASTER_RATIO, MAX_MASTER_RATIO);
+    }
+
+    fn position(&self, window: &K) -> Option<usize> {
+        self.windows.iter().position(|w| w == window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORK_AREA: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 1920,
+        h: 1040,
+    };
+
+    #[test]
+    fn single_window_fills_work_area() {
+        assert_eq!(master_stack_layout(WORK_AREA, 1, 0.5, 0), vec![WORK_AREA]);
+        assert_eq!(
+            master_stack_layout(WORK_AREA, 1, 0.5, 10),
+            vec![Rect::new(10, 10, 1900, 1020)]
+        );
+        assert!(master_stack_layout(WORK_AREA, 0, 0.5, 0).is_empty());
+    }
+
+    #[test]
+    fn master_takes_ratio_of_width() {
+        let rects = master_stack_layout(WORK_AREA, 2, 0.6, 0);
+        assert_eq!(
+            rects,
+            vec![Rect::new(0, 0, 1152, 1040), Rect::new(1152, 0, 768, 1040)]
+        );
+    }
+
+    #[test]
+    fn stack_windows_share_height() {
+        let rects = master_stack_layout(WORK_AREA, 4, 0.5, 0);
+        assert_eq!(rects.len(), 4);
+        assert_eq!(rects[0], Rect::new(0, 0, 960, 1040));
+        // 1040 / 3 = 346 remainder 2: the top two stack windows get a pixel more
+        assert_eq!(rects[1], Rect::new(960, 0, 960, 347));
+        assert_eq!(rects[2], Rect::new(960, 347, 960, 347));
+        assert_eq!(rects[3], Rect::new(960, 694, 960, 346));
+    }
+
+    #[test]
+    fn gaps_separate_windows_and_edges() {
+        let rects = master_stack_layout(WORK_AREA, 3, 0.5, 10);
+        // Inner area 1900x1020, minus one gap between master and stack
+        assert_eq!(rects[0], Rect::new(10, 10, 945, 1020));
+        assert_eq!(rects[1], Rect::new(965, 10, 945, 505));
+        assert_eq!(rects[2], Rect::new(965, 525, 945, 505));
+        assert_eq!(rects[2].bottom(), WORK_AREA.bottom() - 10);
+    }
+
+    #[test]
+    fn ratio_is_clamped() {
+        let rects = master_stack_layout(WORK_AREA, 2, 1.0, 0);
+        assert_eq!(rects[0].w, (1920.0 * MAX_MASTER_RATIO) as i32);
+
+        let mut stack = MasterStack::<u32>::new(0.5);
+        for _ in 0..20 {
+            stack.adjust_ratio(-MASTER_RATIO_STEP);
+        }
+        assert_eq!(stack.ratio(), MIN_MASTER_RATIO);
+    }
+
+    #[test]
+    fn sync_keeps_order_and_appends_new_windows() {
+        let mut stack = MasterStack::new(0.5);
+        stack.sync(&[1, 2, 3]);
+        stack.promote(&3);
+        assert_eq!(stack.windows(), &[3, 1, 2]);
+
+        // Window 1 closed, window 4 opened
+        stack.sync(&[4, 2, 3]);
+        assert_eq!(stack.windows(), &[3, 2, 4]);
+        assert!(stack.contains(&4));
+        assert!(!stack.contains(&1));
+    }
+
+    #[test]
+    fn promote_master_swaps_with_first_stack_window() {
+        let mut stack = MasterStack::new(0.5);
+        stack.sync(&[1, 2, 3]);
+
+        assert!(stack.promote(&1));
+        assert_eq!(stack.windows(), &[2, 1, 3]);
+        assert!(!stack.promote(&9));
+    }
+
+    #[test]
+    fn demote_moves_window_down() {
+        let mut stack = MasterStack::new(0.5);
+        stack.sync(&[1, 2, 3]);
+
+        assert!(stack.demote(&1));
+        assert_eq!(stack.windows(), &[2, 1, 3]);
+
+        // The last window stays last
+        assert!(stack.demote(&3));
+        assert_eq!(stack.windows(), &[2, 1, 3]);
+    }
+}
@@ -3,7 +3,17 @@
 //! This module contains pure business logic that is independent
 //! of Win32 APIs and platform-specific implementations.
 
+pub mod animation;
 pub mod core;
+pub mod cross_monitor_selection;
+pub mod frame;
 pub mod grid;
+pub mod history;
 pub mod keyboard;
+pub mod master_stack;
+pub mod monitor_transfer;
+pub mod placement;
+pub mod rescue;
 pub mod selection;
+pub mod swap;
+pub mod tiling;
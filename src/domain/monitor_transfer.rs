@@ -0,0 +1,251 @@
+//! Moving windows between monitors
+//!
+//! Computes where a window lands when it is thrown to another monitor while
+//! keeping its relative placement. Windows that sit exactly on grid lines of
+//! the source grid are snapped onto the target grid; other windows keep their
+//! fractional position and size within the work area.
+//!
+//! All rectangles are in real pixels. Because placement is proportional to the
+//! work areas, a window covering the left third of a 96 DPI monitor covers the
+//! left third of a 144 DPI monitor too.
+
+use crate::domain::core::Rect;
+use crate::domain::grid::Grid;
+use crate::domain::keyboard::GridCoords;
+
+/// Maximum distance in pixels between a window edge and a grid line to count as aligned
+pub const GRID_ALIGNMENT_TOLERANCE: i32 = 8;
+
+/// Direction in which to move a window through the monitor list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// The next monitor, wrapping from the last to the first
+    Next,
+    /// The previous monitor, wrapping from the first to the last
+    Previous,
+}
+
+/// Returns the index of the adjacent monitor in the given direction
+///
+/// # Arguments
+/// * `index` - Index of the current monitor
+/// * `count` - Number of monitors
+/// * `direction` - Direction to move in
+pub fn adjacent_monitor(index: usize, count: usize, direction: TransferDirection) -> usize {
+    if count == 0 {
+        return index;
+    }
+
+    match direction {
+        TransferDirection::Next => (index + 1) % count,
+        TransferDirection::Previous => (index + count - 1) % count,
+    }
+}
+
+/// Finds the area that holds the largest part of a rectangle
+///
+/// # Returns
+/// Index of the best-overlapping area, or None if the rectangle touches none
+pub fn area_containing(rect: Rect, areas: &[Rect]) -> Option<usize> {
+    areas
+        .iter()
+        .enumerate()
+        .filter_map(|(index, area)| rect.intersection(area).map(|i| (index, i.area())))
+        .max_by_key(|&(index, overlap)| (overlap, std::cmp::Reverse(index)))
+        .map(|(index, _)| index)
+}
+
+/// Returns the grid cells a rectangle covers if its edges lie on grid lines
+///
+/// # Returns
+/// Some((top_left, bottom_right)) if all four edges are within
+/// `GRID_ALIGNMENT_TOLERANCE` of grid lines, None otherwise
+pub fn grid_span(grid: &Grid, rect: Rect) -> Option<(GridCoords, GridCoords)> {
+    let area = grid.screen_area();
+    let (rows, cols) = grid.dimensions();
+    let (cell_width, cell_height) = grid.cell_size();
+
+    let (first_col, end_col) = aligned_span(rect.x, rect.right(), area.x, cell_width, cols)?;
+    let (first_row, end_row) = aligned_span(rect.y, rect.bottom(), area.y, cell_height, rows)?;
+
+    Some((
+        GridCoords::new(first_row, first_col),
+        GridCoords::new(end_row - 1, end_col - 1),
+    ))
+}
+
+/// Computes where a window lands on the target monitor
+///
+/// # Arguments
+/// * `window` - Current window rectangle
+/// * `source` - Grid of the monitor the window is on
+/// * `target` - Grid of the monitor to move the window to
+///
+/// # Returns
+/// The target rectangle, snapped to the target grid if the window was grid-aligned
+pub fn transfer_rect(window: Rect, source: &Grid, target: &Grid) -> Rect {
+    if let Some((top_left, bottom_right)) = grid_span(source, window) {
+        let (source_rows, source_cols) = source.dimensions();
+        let (target_rows, target_cols) = target.dimensions();
+
+        let (first_col, end_col) =
+            map_span(top_left.col, bottom_right.col + 1, source_cols, target_cols);
+        let (first_row, end_row) =
+            map_span(top_left.row, bottom_right.row + 1, source_rows, target_rows);
+
+        if let Ok(rect) = target.coords_to_rect(
+            GridCoords::new(first_row, first_col),
+            GridCoords::new(end_row - 1, end_col - 1),
+        ) {
+            return rect;
+        }
+    }
+
+    fractional_transfer(window, source.screen_area(), target.screen_area())
+}
+
+/// Maps a rectangle between work areas, keeping its fractional position and size
+///
+/// The result is clamped so it stays inside the target work area.
+pub fn fractional_transfer(window: Rect, source_area: Rect, target_area: Rect) -> Rect {
+    let scale_x = target_area.w as f64 / source_area.w.max(1) as f64;
+    let scale_y = target_area.h as f64 / source_area.h.max(1) as f64;
+
+    let w = ((window.w as f64 * scale_x).round() as i32).clamp(1, target_area.w.max(1));
+    let h = ((window.h as f64 * scale_y).round() as i32).clamp(1, target_area.h.max(1));
+
+    let x = target_area.x + ((window.x - source_area.x) as f64 * scale_x).round() as i32;
+    let y = target_area.y + ((window.y - source_area.y) as f64 * scale_y).round() as i32;
+
+    Rect::new(
+        x.clamp(target_area.x, target_area.right() - w),
+        y.clamp(target_area.y, target_area.bottom() - h),
+        w,
+        h,
+    )
+}
+
+/// Finds the cell range [first, end) whose boundaries match the given edges
+fn aligned_span(
+    start: i32,
+    end: i32,
+    origin: i32,
+    cell_size: u32,
+    count: u32,
+) -> Option<(u32, u32)> {
+    let line = |index: u32| origin + (index * cell_size) as i32;
+    let nearest =
+        |edge: i32| (0..=count).find(|&i| (line(i) - edge).abs() <= GRID_ALIGNMENT_TOLERANCE);
+
+    let first = nearest(start)?;
+    let last = nearest(end)?;
+    (last > first).then_some((first, last))
+}
+
+/// Maps a cell range [first, end) of a grid with `from` cells onto one with `to` cells
+///
+/// The range keeps its fractional position and always covers at least one cell.
+fn map_span(first: u32, end: u32, from: u32, to: u32) -> (u32, u32) {
+    let scale = |index: u32| ((index as f64 * to as f64) / from as f64).round() as u32;
+
+    let mapped_first = scale(first).min(to - 1);
+    let mapped_end = scale(end).clamp(mapped_first + 1, to);
+    (mapped_first, mapped_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: u32, cols: u32, area: Rect) -> Grid {
+        Grid::new(rows, cols, area).unwrap()
+    }
+
+    #[test]
+    fn adjacent_monitor_wraps() {
+        assert_eq!(adjacent_monitor(0, 3, TransferDirection::Next), 1);
+        assert_eq!(adjacent_monitor(2, 3, TransferDirection::Next), 0);
+        assert_eq!(adjacent_monitor(0, 3, TransferDirection::Previous), 2);
+        assert_eq!(adjacent_monitor(0, 1, TransferDirection::Next), 0);
+    }
+
+    #[test]
+    fn area_containing_prefers_largest_overlap() {
+        let areas = [Rect::new(0, 0, 1920, 1080), Rect::new(1920, 0, 1920, 1080)];
+
+        assert_eq!(
+            area_containing(Rect::new(1800, 100, 400, 300), &areas),
+            Some(1)
+        );
+        assert_eq!(
+            area_containing(Rect::new(100, 100, 400, 300), &areas),
+            Some(0)
+        );
+        assert_eq!(area_containing(Rect::new(5000, 0, 100, 100), &areas), None);
+    }
+
+    #[test]
+    fn grid_span_detects_aligned_windows() {
+        let source = grid(2, 3, Rect::new(0, 0, 1920, 1080));
+
+        // Q..S exactly, and with a few pixels of frame slack
+        let expected = Some((GridCoords::new(0, 0), GridCoords::new(1, 1)));
+        assert_eq!(grid_span(&source, Rect::new(0, 0, 1280, 1080)), expected);
+        assert_eq!(grid_span(&source, Rect::new(-5, 3, 1290, 1074)), expected);
+
+        assert_eq!(grid_span(&source, Rect::new(100, 100, 800, 600)), None);
+    }
+
+    #[test]
+    fn aligned_window_snaps_to_target_grid() {
+        let source = grid(2, 3, Rect::new(0, 0, 1920, 1080));
+        let target = grid(2, 3, Rect::new(1920, 0, 2560, 1400));
+
+        // Right column, full height
+        let window = Rect::new(1280, 0, 640, 1080);
+        assert_eq!(
+            transfer_rect(window, &source, &target),
+            target
+                .coords_to_rect(GridCoords::new(0, 2), GridCoords::new(1, 2))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn aligned_window_maps_between_grid_sizes() {
+        let source = grid(2, 4, Rect::new(0, 0, 2560, 1400));
+        let target = grid(3, 2, Rect::new(2560, 0, 1080, 1880));
+
+        // Left half of a 4-column grid becomes the left column of a 2-column grid
+        let window = Rect::new(0, 0, 1280, 1400);
+        assert_eq!(
+            transfer_rect(window, &source, &target),
+            target
+                .coords_to_rect(GridCoords::new(0, 0), GridCoords::new(2, 0))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn unaligned_window_keeps_fractional_placement() {
+        let source = grid(2, 3, Rect::new(0, 0, 1920, 1080));
+        let target = grid(2, 3, Rect::new(1920, 0, 3840, 2160));
+
+        let window = Rect::new(192, 108, 960, 540);
+        assert_eq!(
+            transfer_rect(window, &source, &target),
+            Rect::new(2304, 216, 1920, 1080)
+        );
+    }
+
+    #[test]
+    fn fractional_transfer_stays_inside_target() {
+        let source = Rect::new(0, 0, 1920, 1080);
+        let target = Rect::new(1920, 0, 1280, 1024);
+
+        // Window hanging off the right edge of the source
+        let moved = fractional_transfer(Rect::new(1500, 800, 800, 400), source, target);
+        assert!(moved.x >= target.x && moved.right() <= target.right());
+        assert!(moved.y >= target.y && moved.bottom() <= target.bottom());
+    }
+}
@@ -0,0 +1,477 @@
+//! Fitting windows into target rectangles
+//!
+//! Applications may refuse to shrink below a minimum size or grow beyond a
+//! maximum. When that prevents an exact fit, the window keeps the nearest
+//! allowed size and is aligned inside the target according to an `Anchor`.
+//! The result is reported as a `PlacementOutcome` so callers can tell an
+//! exact placement from a constrained one.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::animation::AnimationOptions;
+use crate::domain::core::Rect;
+
+/// Where a window that cannot fill its target is aligned
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Places a rectangle of the given size inside the target
+    ///
+    /// Sizes larger than the target overflow it on the side(s) opposite
+    /// the anchor (or equally on both sides when centered).
+    pub fn place(self, width: i32, height: i32, target: Rect) -> Rect {
+        let left = target.x;
+        let center_x = target.x + (target.w - width) / 2;
+        let right = target.right() - width;
+        let top = target.y;
+        let center_y = target.y + (target.h - height) / 2;
+        let bottom = target.bottom() - height;
+
+        let (x, y) = match self {
+            Anchor::TopLeft => (left, top),
+            Anchor::Top => (center_x, top),
+            Anchor::TopRight => (right, top),
+            Anchor::Left => (left, center_y),
+            Anchor::Center => (center_x, center_y),
+            Anchor::Right => (right, center_y),
+            Anchor::BottomLeft => (left, bottom),
+            Anchor::Bottom => (center_x, bottom),
+            Anchor::BottomRight => (right, bottom),
+        };
+
+        Rect::new(x, y, width, height)
+    }
+}
+
+/// User-configurable placement behavior
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlacementOptions {
+    /// Alignment of windows that cannot fill the selected area exactly
+    pub anchor: Anchor,
+    /// Move non-resizable windows into the selected area keeping their size
+    pub move_non_resizable: bool,
+    /// Maximize the window when the selection covers the whole grid
+    pub maximize_full_grid: bool,
+    /// Move all windows keeping their size, even resizable ones (set by `move_only` rules)
+    pub move_only: bool,
+    /// Animated transition into the selected area
+    pub animation: AnimationOptions,
+}
+
+impl Default for PlacementOptions {
+    fn default() -> Self {
+        Self {
+            anchor: Anchor::default(),
+            move_non_resizable: true,
+            maximize_full_grid: true,
+            move_only: false,
+            animation: AnimationOptions::default(),
+        }
+    }
+}
+
+/// What to do with a window's maximized state when placing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowStateAction {
+    /// Maximize the window instead of sizing it to the grid
+    Maximize,
+    /// Restore the maximized window with the target as its restored bounds
+    RestoreToTarget,
+    /// Place the window normally
+    Place,
+}
+
+/// Decides how a window's maximized state is handled for a selection
+///
+/// # Arguments
+/// * `covers_whole_grid` - Whether the selection spans every grid cell
+/// * `is_maximized` - Whether the window is currently maximized
+/// * `options` - Placement options
+pub fn window_state_action(
+    covers_whole_grid: bool,
+    is_maximized: bool,
+    options: &PlacementOptions,
+) -> WindowStateAction {
+    if covers_whole_grid && options.maximize_full_grid {
+        WindowStateAction::Maximize
+    } else if is_maximized {
+        WindowStateAction::RestoreToTarget
+    } else {
+        WindowStateAction::Place
+    }
+}
+
+/// Converts a screen rectangle into workspace coordinates
+///
+/// WINDOWPLACEMENT stores restored bounds relative to the work area of the
+/// monitor rather than the screen, so a taskbar on the top or left edge
+/// shifts the origin.
+///
+/// # Arguments
+/// * `rect` - Rectangle in screen coordinates
+/// * `monitor_rect` - Full bounds of the monitor containing the rectangle
+/// * `work_area` - Work area of that monitor
+pub fn to_workspace(rect: Rect, monitor_rect: Rect, work_area: Rect) -> Rect {
+    Rect::new(
+        rect.x - (work_area.x - monitor_rect.x),
+        rect.y - (work_area.y - monitor_rect.y),
+        rect.w,
+        rect.h,
+    )
+}
+
+/// Converts a rectangle in workspace coordinates back into screen coordinates
+///
+/// Inverse of `to_workspace`.
+///
+/// # Arguments
+/// * `rect` - Rectangle in workspace coordinates
+/// * `monitor_rect` - Full bounds of the monitor the workspace belongs to
+/// * `work_area` - Work area of that monitor
+pub fn from_workspace(rect: Rect, monitor_rect: Rect, work_area: Rect) -> Rect {
+    Rect::new(
+        rect.x + (work_area.x - monitor_rect.x),
+        rect.y + (work_area.y - monitor_rect.y),
+        rect.w,
+        rect.h,
+    )
+}
+
+/// How a particular window is placed into the selected area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementStrategy {
+    /// Move and resize the window to cover the target
+    Resize,
+    /// Keep the window size and anchor it inside the target
+    MoveOnly,
+    /// Leave the window untouched
+    Skip,
+}
+
+/// Minimum and maximum window sizes an application accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeConstraints {
+    pub min_width: i32,
+    pub min_height: i32,
+    pub max_width: i32,
+    pub max_height: i32,
+}
+
+impl SizeConstraints {
+    /// Constraints that accept any size
+    #[cfg(test)]
+    pub fn unconstrained() -> Self {
+        Self {
+            min_width: 0,
+            min_height: 0,
+            max_width: i32::MAX,
+            max_height: i32::MAX,
+        }
+    }
+
+    /// Constraints that only accept the given size
+    pub fn fixed(width: i32, height: i32) -> Self {
+        Self {
+            min_width: width,
+            min_height: height,
+            max_width: width,
+            max_height: height,
+        }
+    }
+
+    /// Returns the allowed size nearest to the requested one
+    pub fn clamp(&self, width: i32, height: i32) -> (i32, i32) {
+        // max() last so a minimum wins over an inconsistent maximum
+        (
+            width.min(self.max_width).max(self.min_width),
+            height.min(self.max_height).max(self.min_height),
+        )
+    }
+}
+
+/// Result of placing a window into a target rectangle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementOutcome {
+    /// The window covers the target exactly
+    Exact { rect: Rect },
+    /// Size constraints prevented an exact fit; the window is anchored in the target
+    Constrained { target: Rect, rect: Rect },
+    /// The window cannot be resized; it kept its size and is anchored in the target
+    Moved { target: Rect, rect: Rect },
+    /// The selection covered the whole grid and the window was maximized
+    Maximized { target: Rect },
+}
+
+impl PlacementOutcome {
+    /// Returns the rectangle the window was placed at
+    pub fn rect(&self) -> Rect {
+        match self {
+            PlacementOutcome::Exact { rect }
+            | PlacementOutcome::Constrained { rect, .. }
+            | PlacementOutcome::Moved { rect, .. } => *rect,
+            PlacementOutcome::Maximized { target } => *target,
+        }
+    }
+
+    /// Returns true if the window extends beyond its target
+    pub fn overflows(&self) -> bool {
+        match self {
+            PlacementOutcome::Exact { .. } | PlacementOutcome::Maximized { .. } => false,
+            PlacementOutcome::Constrained { target, rect }
+            | PlacementOutcome::Moved { target, rect } => rect.w > target.w || rect.h > target.h,
+        }
+    }
+}
+
+impl std::fmt::Display for PlacementOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlacementOutcome::Exact { rect } => {
+                write!(f, "placed at ({},{}) {}x{}", rect.x, rect.y, rect.w, rect.h)
+            }
+            PlacementOutcome::Constrained { target, rect } => write!(
+                f,
+                "constrained to ({},{}) {}x{} (requested {}x{})",
+                rect.x, rect.y, rect.w, rect.h, target.w, target.h
+            ),
+            PlacementOutcome::Moved { rect, .. } => write!(
+                f,
+                "moved to ({},{}) keeping size {}x{}",
+                rect.x, rect.y, rect.w, rect.h
+            ),
+            PlacementOutcome::Maximized { .. } => write!(f, "maximized"),
+        }
+    }
+}
+
+/// Fits a window with size constraints into a target rectangle
+///
+/// # Arguments
+/// * `target` - Rectangle the window should cover
+/// * `constraints` - Sizes the window accepts
+/// * `anchor` - Alignment used when the window cannot cover the target exactly
+///
+/// # Returns
+/// Exact if the target size is allowed, Constrained with the anchored rect otherwise
+pub fn fit_to_target(
+    target: Rect,
+    constraints: SizeConstraints,
+    anchor: Anchor,
+) -> PlacementOutcome {
+    let (width, height) = constraints.clamp(target.w, target.h);
+
+    if (width, height) == (target.w, target.h) {
+        PlacementOutcome::Exact { rect: target }
+    } else {
+        PlacementOutcome::Constrained {
+            target,
+            rect: anchor.place(width, height, target),
+        }
+    }
+}
+
+/// Moves a window of fixed size into a target rectangle
+///
+/// # Arguments
+/// * `target` - Rectangle the window should sit in
+/// * `width`, `height` - Current window size, which is kept
+/// * `anchor` - Alignment inside the target
+pub fn move_into_target(target: Rect, width: i32, height: i32, anchor: Anchor) -> PlacementOutcome {
+    PlacementOutcome::Moved {
+        target,
+        rect: anchor.place(width, height, target),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TARGET: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 640,
+        h: 540,
+    };
+
+    #[test]
+    fn unconstrained_window_fits_exactly() {
+        let outcome = fit_to_target(TARGET, SizeConstraints::unconstrained(), Anchor::Center);
+        assert_eq!(outcome, PlacementOutcome::Exact { rect: TARGET });
+        assert!(!outcome.overflows());
+    }
+
+    #[test]
+    fn minimum_size_overflows_from_anchor() {
+        let constraints = SizeConstraints {
+            min_width: 800,
+            ..SizeConstraints::unconstrained()
+        };
+
+        let outcome = fit_to_target(TARGET, constraints, Anchor::TopLeft);
+        assert_eq!(outcome.rect(), Rect::new(0, 0, 800, 540));
+        assert!(outcome.overflows());
+
+        let outcome = fit_to_target(TARGET, constraints, Anchor::Center);
+        assert_eq!(outcome.rect(), Rect::new(-80, 0, 800, 540));
+    }
+
+    #[test]
+    fn maximum_size_is_anchored_inside_target() {
+        let constraints = SizeConstraints {
+            max_width: 400,
+            max_height: 300,
+            ..SizeConstraints::unconstrained()
+        };
+
+        let outcome = fit_to_target(TARGET, constraints, Anchor::BottomRight);
+        assert_eq!(
+            outcome,
+            PlacementOutcome::Constrained {
+                target: TARGET,
+                rect: Rect::new(240, 240, 400, 300),
+            }
+        );
+        assert!(!outcome.overflows());
+    }
+
+    #[test]
+    fn anchors_place_on_expected_edges() {
+        let target = Rect::new(100, 100, 600, 400);
+
+        assert_eq!(
+            Anchor::Top.place(200, 100, target),
+            Rect::new(300, 100, 200, 100)
+        );
+        assert_eq!(
+            Anchor::Left.place(200, 100, target),
+            Rect::new(100, 250, 200, 100)
+        );
+        assert_eq!(
+            Anchor::Right.place(200, 100, target),
+            Rect::new(500, 250, 200, 100)
+        );
+        assert_eq!(
+            Anchor::Bottom.place(200, 100, target),
+            Rect::new(300, 400, 200, 100)
+        );
+    }
+
+    #[test]
+    fn moved_window_keeps_its_size() {
+        let outcome = move_into_target(TARGET, 400, 200, Anchor::Center);
+        assert_eq!(
+            outcome,
+            PlacementOutcome::Moved {
+                target: TARGET,
+                rect: Rect::new(120, 170, 400, 200),
+            }
+        );
+        assert!(!outcome.overflows());
+        assert!(move_into_target(TARGET, 700, 200, Anchor::TopLeft).overflows());
+    }
+
+    #[test]
+    fn full_grid_selection_maximizes() {
+        let options = PlacementOptions::default();
+
+        assert_eq!(
+            window_state_action(true, false, &options),
+            WindowStateAction::Maximize
+        );
+        assert_eq!(
+            window_state_action(true, true, &options),
+            WindowStateAction::Maximize
+        );
+    }
+
+    #[test]
+    fn maximized_window_restores_to_target() {
+        let options = PlacementOptions::default();
+        assert_eq!(
+            window_state_action(false, true, &options),
+            WindowStateAction::RestoreToTarget
+        );
+        assert_eq!(
+            window_state_action(false, false, &options),
+            WindowStateAction::Place
+        );
+
+        // With maximizing disabled, a full-grid selection is placed like any other
+        let options = PlacementOptions {
+            maximize_full_grid: false,
+            ..PlacementOptions::default()
+        };
+        assert_eq!(
+            window_state_action(true, true, &options),
+            WindowStateAction::RestoreToTarget
+        );
+        assert_eq!(
+            window_state_action(true, false, &options),
+            WindowStateAction::Place
+        );
+    }
+
+    #[test]
+    fn workspace_coordinates_account_for_taskbar() {
+        let monitor = Rect::new(1920, 0, 1920, 1080);
+        let rect = Rect::new(1920, 40, 960, 1040);
+
+        // Taskbar at the top of the monitor shifts the workspace origin down
+        let work_area = Rect::new(1920, 40, 1920, 1040);
+        assert_eq!(
+            to_workspace(rect, monitor, work_area),
+            Rect::new(1920, 0, 960, 1040)
+        );
+
+        // Taskbar at the bottom leaves coordinates unchanged
+        let work_area = Rect::new(1920, 0, 1920, 1040);
+        assert_eq!(to_workspace(rect, monitor, work_area), rect);
+    }
+
+    #[test]
+    fn workspace_coordinates_convert_back_to_screen() {
+        let monitor = Rect::new(0, 0, 1920, 1080);
+        let rect = Rect::new(100, 100, 800, 600);
+
+        // Taskbar on the left edge
+        let work_area = Rect::new(48, 0, 1872, 1080);
+        let workspace = to_workspace(rect, monitor, work_area);
+        assert_eq!(workspace, Rect::new(52, 100, 800, 600));
+        assert_eq!(from_workspace(workspace, monitor, work_area), rect);
+    }
+
+    #[test]
+    fn minimum_wins_over_inconsistent_maximum() {
+        let constraints = SizeConstraints {
+            min_width: 500,
+            min_height: 0,
+            max_width: 300,
+            max_height: i32::MAX,
+        };
+        assert_eq!(constraints.clamp(640, 540), (500, 540));
+    }
+
+    #[test]
+    fn anchor_deserializes_from_snake_case() {
+        let options: PlacementOptions =
+            serde_json::from_str(r#"{ "anchor": "top_left" }"#).unwrap();
+        assert_eq!(options.anchor, Anchor::TopLeft);
+        assert!(options.move_non_resizable);
+        assert_eq!(PlacementOptions::default().anchor, Anchor::Center);
+    }
+}
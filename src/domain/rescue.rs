@@ -0,0 +1,171 @@
+//! Rescue of off-screen windows
+//!
+//! After a monitor is disconnected, Windows can leave windows at coordinates
+//! that no monitor covers. These functions detect such windows by how much
+//! of them is visible on any work area and compute where to bring them back,
+//! preserving their size when it fits.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::core::Rect;
+
+/// User-configurable off-screen window rescue
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RescueOptions {
+    /// Gather off-screen windows automatically when the display set changes
+    pub on_display_change: bool,
+    /// Windows with less than this fraction of their area on a work area are rescued
+    pub min_visible_fraction: f64,
+}
+
+impl Default for RescueOptions {
+    fn default() -> Self {
+        Self {
+            on_display_change: false,
+            min_visible_fraction: 0.25,
+        }
+    }
+}
+
+/// Returns the fraction of a rectangle that lies on any of the areas
+///
+/// Work areas of different monitors never overlap, so the visible parts
+/// are summed directly.
+pub fn visible_fraction(rect: Rect, areas: &[Rect]) -> f64 {
+    if rect.w <= 0 || rect.h <= 0 {
+        return 0.0;
+    }
+
+    let visible: i64 = areas
+        .iter()
+        .filter_map(|area| rect.intersection(area))
+        .map(|i| i64::from(i.w) * i64::from(i.h))
+        .sum();
+    visible as f64 / (i64::from(rect.w) * i64::from(rect.h)) as f64
+}
+
+/// Returns true if too little of a window is visible
+///
+/// # Arguments
+/// * `rect` - Window rectangle (visible frame)
+/// * `areas` - Work areas of all connected monitors
+/// * `min_visible_fraction` - Minimum visible fraction of the window area
+pub fn needs_rescue(rect: Rect, areas: &[Rect], min_visible_fraction: f64) -> bool {
+    visible_fraction(rect, areas) < min_visible_fraction
+}
+
+/// Finds the area closest to a rectangle
+///
+/// Distance is measured from the rectangle's center to the nearest point of
+/// each area; ties go to the first area.
+///
+/// # Returns
+/// Index of the nearest area, or None if there are no areas
+pub fn nearest_area(rect: Rect, areas: &[Rect]) -> Option<usize> {
+    let center_x = i64::from(rect.x) + i64::from(rect.w) / 2;
+    let center_y = i64::from(rect.y) + i64::from(rect.h) / 2;
+
+    areas
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, area)| {
+            let dx = axis_distance(center_x, area.x, area.right());
+            let dy = axis_distance(center_y, area.y, area.bottom());
+            dx * dx + dy * dy
+        })
+        .map(|(index, _)| index)
+}
+
+/// Distance from a coordinate to the range [start, end) along one axis
+fn axis_distance(value: i64, start: i32, end: i32) -> i64 {
+    if value < i64::from(start) {
+        i64::from(start) - value
+    } else if value >= i64::from(end) {
+        value - i64::from(end) + 1
+    } else {
+        0
+    }
+}
+
+/// Computes where to move a rescued window inside an area
+///
+/// The window keeps its size if it fits and is moved the shortest distance
+/// needed to lie fully inside the area; otherwise it is shrunk to the area.
+pub fn rescue_rect(rect: Rect, area: Rect) -> Rect {
+    let w = rect.w.clamp(1, area.w.max(1));
+    let h = rect.h.clamp(1, area.h.max(1));
+    let x = rect.x.clamp(area.x, area.right() - w);
+    let y = rect.y.clamp(area.y, area.bottom() - h);
+    Rect::new(x, y, w, h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 1920,
+        h: 1040,
+    };
+    const RIGHT: Rect = Rect {
+        x: 1920,
+        y: 0,
+        w: 2560,
+        h: 1400,
+    };
+
+    #[test]
+    fn visible_fraction_sums_all_areas() {
+        // Window straddling both monitors is fully visible
+        let straddling = Rect::new(1420, 100, 1000, 500);
+        assert_eq!(visible_fraction(straddling, &[LEFT, RIGHT]), 1.0);
+
+        // Only the left half remains once the right monitor is gone
+        assert_eq!(visible_fraction(straddling, &[LEFT]), 0.5);
+    }
+
+    #[test]
+    fn windows_on_removed_monitor_need_rescue() {
+        let orphan = Rect::new(2500, 200, 1200, 800);
+        assert!(needs_rescue(orphan, &[LEFT], 0.25));
+        assert!(!needs_rescue(orphan, &[LEFT, RIGHT], 0.25));
+
+        // A sliver at the edge is not enough
+        let sliver = Rect::new(1900, 0, 800, 600);
+        assert!(needs_rescue(sliver, &[LEFT], 0.25));
+    }
+
+    #[test]
+    fn degenerate_windows_are_not_visible() {
+        assert_eq!(visible_fraction(Rect::new(0, 0, 0, 0), &[LEFT]), 0.0);
+    }
+
+    #[test]
+    fn nearest_area_uses_distance_from_center() {
+        let above_right = Rect::new(2000, -3000, 400, 300);
+        assert_eq!(nearest_area(above_right, &[LEFT, RIGHT]), Some(1));
+
+        let far_left = Rect::new(-5000, 500, 800, 600);
+        assert_eq!(nearest_area(far_left, &[LEFT, RIGHT]), Some(0));
+
+        assert_eq!(nearest_area(far_left, &[]), None);
+    }
+
+    #[test]
+    fn rescued_window_keeps_size_when_it_fits() {
+        let orphan = Rect::new(2500, 200, 1200, 800);
+        assert_eq!(rescue_rect(orphan, LEFT), Rect::new(720, 200, 1200, 800));
+
+        let above = Rect::new(100, -900, 800, 600);
+        assert_eq!(rescue_rect(above, LEFT), Rect::new(100, 0, 800, 600));
+    }
+
+    #[test]
+    fn oversized_window_is_shrunk_to_area() {
+        let huge = Rect::new(3000, 0, 2560, 1400);
+        assert_eq!(rescue_rect(huge, LEFT), LEFT);
+    }
+}
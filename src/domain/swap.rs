@@ -0,0 +1,152 @@
+//! Swapping windows between grid zones
+//!
+//! Finds the window occupying each of two zones by best overlap and plans
+//! the moves that make them trade places. When one zone is empty, the
+//! occupant of the other zone simply moves there.
+
+use crate::domain::core::Rect;
+
+/// Fraction of the smaller of window and zone that must overlap for the
+/// window to count as occupying the zone
+pub const MIN_OCCUPANCY: f64 = 0.5;
+
+/// Errors that can occur when planning a swap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwapError {
+    /// No window occupies either zone
+    NoWindows,
+    /// The same window occupies both zones (e.g. it spans both)
+    SameWindow,
+}
+
+/// Finds the window occupying a zone
+///
+/// # Arguments
+/// * `zone` - Zone rectangle in screen coordinates
+/// * `windows` - Window rectangles (visible frames) in z-order, topmost first
+///
+/// # Returns
+/// Index of the window with the largest overlap, or None if no window
+/// overlaps enough. Ties go to the topmost window.
+pub fn zone_occupant(zone: Rect, windows: &[Rect]) -> Option<usize> {
+    let mut best: Option<(usize, i64)> = None;
+    for (index, window) in windows.iter().enumerate() {
+        let Some(overlap) = zone.intersection(window) else {
+            continue;
+        };
+        let overlap_area = area(overlap);
+        let smaller = area(zone).min(area(*window));
+        if smaller == 0 || (overlap_area as f64) < smaller as f64 * MIN_OCCUPANCY {
+            continue;
+        }
+        if best.is_none_or(|(_, best_area)| overlap_area > best_area) {
+            best = Some((index, overlap_area));
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+/// Plans the moves that swap the occupants of two zones
+///
+/// # Arguments
+/// * `first` - First zone rectangle
+/// * `second` - Second zone rectangle
+/// * `windows` - Window rectangles (visible frames) in z-order, topmost first
+///
+/// # Returns
+/// (window index, target zone) for each window to move, or SwapError
+pub fn swap_moves(
+    first: Rect,
+    second: Rect,
+    windows: &[Rect],
+) -> Result<Vec<(usize, Rect)>, SwapError> {
+    match (
+        zone_occupant(first, windows),
+        zone_occupant(second, windows),
+    ) {
+        (None, None) => Err(SwapError::NoWindows),
+        (Some(a), Some(b)) if a == b => Err(SwapError::SameWindow),
+        (Some(a), Some(b)) => Ok(vec![(a, second), (b, first)]),
+        (Some(a), None) => Ok(vec![(a, second)]),
+        (None, Some(b)) => Ok(vec![(b, first)]),
+    }
+}
+
+fn area(rect: Rect) -> i64 {
+    i64::from(rect.w.max(0)) * i64::from(rect.h.max(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: Rect = Rect {
+        x: 0,
+        y: 0,
+        w: 960,
+        h: 1040,
+    };
+    const RIGHT: Rect = Rect {
+        x: 960,
+        y: 0,
+        w: 960,
+        h: 1040,
+    };
+
+    #[test]
+    fn occupant_has_largest_overlap() {
+        let editor = Rect::new(10, 10, 940, 1000);
+        let browser = Rect::new(900, 0, 1020, 1040);
+        assert_eq!(zone_occupant(LEFT, &[browser, editor]), Some(1));
+        assert_eq!(zone_occupant(RIGHT, &[browser, editor]), Some(0));
+    }
+
+    #[test]
+    fn small_window_inside_zone_occupies_it() {
+        let dialog = Rect::new(300, 300, 400, 300);
+        assert_eq!(zone_occupant(LEFT, &[dialog]), Some(0));
+    }
+
+    #[test]
+    fn sliver_does_not_occupy_zone() {
+        // Only 60 of the window's 1020 pixels of width reach into the left zone
+        let browser = Rect::new(900, 0, 1020, 1040);
+        assert_eq!(zone_occupant(LEFT, &[browser]), None);
+        assert_eq!(zone_occupant(LEFT, &[]), None);
+    }
+
+    #[test]
+    fn ties_go_to_topmost_window() {
+        let top = Rect::new(0, 0, 960, 1040);
+        let below = Rect::new(0, 0, 960, 1040);
+        assert_eq!(zone_occupant(LEFT, &[top, below]), Some(0));
+    }
+
+    #[test]
+    fn occupants_trade_zones() {
+        let editor = Rect::new(0, 0, 960, 1040);
+        let browser = Rect::new(960, 0, 960, 1040);
+        assert_eq!(
+            swap_moves(LEFT, RIGHT, &[browser, editor]),
+            Ok(vec![(1, RIGHT), (0, LEFT)])
+        );
+    }
+
+    #[test]
+    fn single_occupant_moves_to_empty_zone() {
+        let editor = Rect::new(0, 0, 960, 1040);
+        assert_eq!(swap_moves(LEFT, RIGHT, &[editor]), Ok(vec![(0, RIGHT)]));
+        assert_eq!(swap_moves(RIGHT, LEFT, &[editor]), Ok(vec![(0, RIGHT)]));
+    }
+
+    #[test]
+    fn swap_needs_distinct_windows() {
+        assert_eq!(swap_moves(LEFT, RIGHT, &[]), Err(SwapError::NoWindows));
+
+        let maximized = Rect::new(0, 0, 1920, 1040);
+        assert_eq!(
+            swap_moves(LEFT, RIGHT, &[maximized]),
+            Err(SwapError::SameWindow)
+        );
+    }
+}
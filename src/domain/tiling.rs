@@ -0,0 +1,213 @@
+//! Tiling of several windows into a grid
+//!
+//! Assigns each window a cell or a span of cells so a set of windows fills
+//! the whole grid. The assignment is deterministic and depends only on the
+//! grid dimensions and the number of windows, so the first window (topmost
+//! in z-order) always gets the top-left, largest span.
+//!
+//! ## Algorithm
+//! - At least as many windows as cells: one cell per window in row-major
+//!   fill order; windows beyond the cell count are not tiled.
+//! - Fewer windows than cells: columns are divided between the windows
+//!   first (full-height spans); when there are more windows than columns,
+//!   each column is split into rows. Leftover columns and rows go to the
+//!   first windows.
+
+use crate::domain::keyboard::GridCoords;
+
+/// Cell span assigned to one window: top-left and bottom-right cells (inclusive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileSpan {
+    pub start: GridCoords,
+    pub end: GridCoords,
+}
+
+impl TileSpan {
+    /// Creates a tile span from its corner cells
+    pub fn new(start: GridCoords, end: GridCoords) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the number of cells covered by the span
+    #[cfg(test)]
+    pub fn cell_count(&self) -> u32 {
+        (self.end.row - self.start.row + 1) * (self.end.col - self.start.col + 1)
+    }
+}
+
+/// Assigns grid spans to windows
+///
+/// # Arguments
+/// * `rows` - Number of grid rows
+/// * `cols` - Number of grid columns
+/// * `window_count` - Number of windows to tile, in priority order
+///
+/// # Returns
+/// One span per tiled window, in the same order as the windows. Contains
+/// `min(window_count, rows * cols)` spans that never overlap.
+pub fn tile_spans(rows: u32, cols: u32, window_count: usize) -> Vec<TileSpan> {
+    if rows == 0 || cols == 0 || window_count == 0 {
+        return Vec::new();
+    }
+
+    let cells = (rows * cols) as usize;
+    if window_count >= cells {
+        return (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| GridCoords::new(row, col)))
+            .map(|cell| TileSpan::new(cell, cell))
+            .collect();
+    }
+
+    let count = window_count as u32;
+    if count <= cols {
+        return split_evenly(cols, count)
+            .into_iter()
+            .map(|(col, width)| {
+                TileSpan::new(
+                    GridCoords::new(0, col),
+                    GridCoords::new(rows - 1, col + width - 1),
+                )
+            })
+            .collect();
+    }
+
+    // More windows than columns: stack windows within each column
+    split_evenly(count, cols)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(col, (_, windows_in_column))| {
+            let col = col as u32;
+            split_evenly(rows, windows_in_column)
+                .into_iter()
+                .map(move |(row, height)| {
+                    TileSpan::new(
+                        GridCoords::new(row, col),
+                        GridCoords::new(row + height - 1, col),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Splits `total` units into `parts` contiguous runs as evenly as possible
+///
+/// # Returns
+/// (start, length) of each run; the first `total % parts` runs are one unit longer
+fn split_evenly(total: u32, parts: u32) -> Vec<(u32, u32)> {
+    let base = total / parts;
+    let extra = total % parts;
+
+    let mut start = 0;
+    (0..parts)
+        .map(|part| {
+            let length = base + u32::from(part < extra);
+            let run = (start, length);
+            start += length;
+            run
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(start: (u32, u32), end: (u32, u32)) -> TileSpan {
+        TileSpan::new(
+            GridCoords::new(start.0, start.1),
+            GridCoords::new(end.0, end.1),
+        )
+    }
+
+    /// Checks that spans stay within the grid and never overlap
+    fn assert_disjoint(rows: u32, cols: u32, spans: &[TileSpan]) {
+        let mut covered = vec![false; (rows * cols) as usize];
+        for s in spans {
+            assert!(s.end.row < rows && s.end.col < cols, "{:?} outside grid", s);
+            for row in s.start.row..=s.end.row {
+                for col in s.start.col..=s.end.col {
+                    let cell = (row * cols + col) as usize;
+                    assert!(!covered[cell], "cell ({},{}) assigned twice", row, col);
+                    covered[cell] = true;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_window_fills_grid() {
+        assert_eq!(tile_spans(2, 3, 1), vec![span((0, 0), (1, 2))]);
+    }
+
+    #[test]
+    fn few_windows_split_columns() {
+        // 3x2 grid, two windows: left window gets the extra column
+        assert_eq!(
+            tile_spans(2, 3, 2),
+            vec![span((0, 0), (1, 1)), span((0, 2), (1, 2))]
+        );
+        assert_eq!(
+            tile_spans(2, 3, 3),
+            vec![
+                span((0, 0), (1, 0)),
+                span((0, 1), (1, 1)),
+                span((0, 2), (1, 2))
+            ]
+        );
+    }
+
+    #[test]
+    fn more_windows_than_columns_stack_in_columns() {
+        // 3x2 grid, four windows: first column is split, others stay full height
+        assert_eq!(
+            tile_spans(2, 3, 4),
+            vec![
+                span((0, 0), (0, 0)),
+                span((1, 0), (1, 0)),
+                span((0, 1), (1, 1)),
+                span((0, 2), (1, 2))
+            ]
+        );
+    }
+
+    #[test]
+    fn full_grid_uses_row_major_fill_order() {
+        let spans = tile_spans(2, 2, 4);
+        assert_eq!(
+            spans,
+            vec![
+                span((0, 0), (0, 0)),
+                span((0, 1), (0, 1)),
+                span((1, 0), (1, 0)),
+                span((1, 1), (1, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn extra_windows_are_not_tiled() {
+        assert_eq!(tile_spans(2, 3, 10).len(), 6);
+    }
+
+    #[test]
+    fn no_windows_or_empty_grid() {
+        assert!(tile_spans(2, 3, 0).is_empty());
+        assert!(tile_spans(0, 3, 2).is_empty());
+    }
+
+    #[test]
+    fn spans_cover_grid_without_overlap() {
+        for rows in 1..=4 {
+            for cols in 1..=5 {
+                let cells = (rows * cols) as usize;
+                for count in 1..=cells {
+                    let spans = tile_spans(rows, cols, count);
+                    assert_eq!(spans.len(), count);
+                    assert_disjoint(rows, cols, &spans);
+                    let covered: u32 = spans.iter().map(TileSpan::cell_count).sum();
+                    assert_eq!(covered, rows * cols, "{}x{} with {}", cols, rows, count);
+                }
+            }
+        }
+    }
+}
@@ -50,6 +50,8 @@ pub enum KeyEvent {
     GridKey(char),
     /// Navigation between monitors
     Navigation(NavigationDirection),
+    /// Pick the next window as the target of the selection
+    CycleTarget,
     /// Cancel selection
     Cancel,
     /// Invalid key (for debugging)
@@ -96,6 +98,9 @@ impl KeyEvent {
             0x26 => Some(KeyEvent::Navigation(NavigationDirection::Up)),   // VK_UP
             0x28 => Some(KeyEvent::Navigation(NavigationDirection::Down)), // VK_DOWN
 
+            // Target window cycling
+            0x09 => Some(KeyEvent::CycleTarget), // VK_TAB
+
             // Cancel keys
             0x1b => Some(KeyEvent::Cancel), // VK_ESCAPE
 
@@ -312,6 +317,9 @@ mod tests {
         // Test cancel key
         assert_eq!(KeyEvent::from_vk_code(0x1b), Some(KeyEvent::Cancel));
 
+        // Test target cycling key
+        assert_eq!(KeyEvent::from_vk_code(0x09), Some(KeyEvent::CycleTarget));
+
         // Test invalid key
         assert_eq!(
             KeyEvent::from_vk_code(0x01),
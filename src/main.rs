@@ -3,14 +3,24 @@
 //! Phase 1: Infrastructure (DPI awareness, monitor enumeration, window management) ✓
 //! Phase 2: Domain Logic (keyboard layout, grid geometry, selection process) ✓
 
+// Without the Win32 front end only the render mode is reachable
+#![cfg_attr(not(windows), allow(dead_code))]
+
+#[cfg(windows)]
 use windows::Win32::Foundation::*;
+#[cfg(windows)]
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+#[cfg(windows)]
 use windows::Win32::UI::HiDpi::*;
+#[cfg(windows)]
 use windows::Win32::UI::WindowsAndMessaging::*;
+#[cfg(windows)]
 use windows::core::PCWSTR;
 
 mod app;
+mod config;
 mod domain;
+#[cfg(windows)]
 mod input;
 mod platform;
 mod ui;
@@ -18,6 +28,7 @@ mod ui;
 use domain::core::Rect;
 use domain::grid::Grid;
 use domain::keyboard::GridCoords;
+use domain::placement::PlacementOptions;
 use domain::selection::Selection;
 use platform::{monitors, window};
 
@@ -26,9 +37,20 @@ const DEFAULT_GRID_COLS: u32 = 3;
 const DEFAULT_GRID_ROWS: u32 = 2;
 const MIN_CELL_WIDTH: i32 = 480;
 const MIN_CELL_HEIGHT: i32 = 350;
-const MIN_MONITOR_HEIGHT: i32 = 600;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Headless preview mode: renders an overlay to PNG without touching the desktop
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "render") {
+        return ui::preview::run(&args[1..]).map_err(Into::into);
+    }
+
+    run_application()
+}
+
+/// Runs the window positioning application until it is asked to quit
+#[cfg(windows)]
+fn run_application() -> Result<(), Box<dyn std::error::Error>> {
     // CRITICAL: Set DPI awareness before any other Windows API calls
     // This ensures our application gets real pixel coordinates instead of scaled ones
     unsafe {
@@ -62,9 +84,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Window positioning needs Win32; other platforms only support the render mode
+#[cfg(not(windows))]
+fn run_application() -> Result<(), Box<dyn std::error::Error>> {
+    Err("Window positioning is only supported on Windows; use `tactile-win render` to preview overlays".into())
+}
+
 /// Creates a minimal hidden window for message processing
 ///
 /// This window is needed to receive messages from the keyboard hook
+#[cfg(windows)]
 fn create_main_window() -> Result<HWND, Box<dyn std::error::Error>> {
     unsafe {
         let instance = GetModuleHandleW(PCWSTR::null())?;
@@ -116,6 +145,7 @@ fn create_main_window() -> Result<HWND, Box<dyn std::error::Error>> {
 
 /// Window procedure for the main window
 /// Handles keyboard events from the low-level keyboard hook
+#[cfg(windows)]
 unsafe extern "system" fn window_proc(
     hwnd: HWND,
     msg: u32,
@@ -135,6 +165,19 @@ unsafe extern "system" fn window_proc(
         // TODO: Call controller.handle_keyboard_event(wparam) once we can access controller
     }
 
+    // WM_DISPLAYCHANGE is sent directly to this procedure; forward it to the
+    // controller's event loop so grids can be rebuilt for the new monitor set
+    if msg == WM_DISPLAYCHANGE {
+        let _ = unsafe {
+            PostMessageW(
+                hwnd,
+                app::controller::WM_TACTILE_DISPLAY_CHANGED,
+                WPARAM(0),
+                LPARAM(0),
+            )
+        };
+    }
+
     unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) }
 }
 
@@ -263,7 +306,7 @@ fn run_phase1_validation() -> Result<(), Box<dyn std::error::Error>> {
             MIN_CELL_HEIGHT,
         );
 
-        let should_reject = monitor.should_reject(MIN_MONITOR_HEIGHT);
+        let should_reject = monitor.should_reject(Grid::MIN_MONITOR_HEIGHT);
 
         println!("      Can support 3x2 grid: {}", can_support_grid);
         println!("      Should reject (too small): {}", should_reject);
@@ -284,7 +327,7 @@ fn run_phase1_validation() -> Result<(), Box<dyn std::error::Error>> {
                 DEFAULT_GRID_ROWS,
                 MIN_CELL_WIDTH,
                 MIN_CELL_HEIGHT,
-            ) && !m.should_reject(MIN_MONITOR_HEIGHT)
+            ) && !m.should_reject(Grid::MIN_MONITOR_HEIGHT)
         })
         .collect();
 
@@ -354,8 +397,12 @@ fn demo_window_positioning() -> Result<(), Box<dyn std::error::Error>> {
         target_rect.w, target_rect.h, target_rect.x, target_rect.y
     );
 
-    window::position_window(window_info.handle, target_rect)?;
-    println!("Window positioned successfully!");
+    let outcome = window::position_window(
+        window_info.handle,
+        target_rect,
+        &PlacementOptions::default(),
+    )?;
+    println!("Window positioned successfully: {}", outcome);
 
     Ok(())
 }
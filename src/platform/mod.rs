@@ -3,6 +3,9 @@
 //! This module encapsulates all Win32 API interactions and provides
 //! a clean interface to the rest of the application.
 
+pub mod monitor_id;
 pub mod monitors;
+pub mod orientation;
 pub mod window;
+pub mod window_events;
 pub mod windows;
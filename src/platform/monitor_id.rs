@@ -0,0 +1,106 @@
+//! Stable monitor identification
+//!
+//! Monitor indices and `HMONITOR` handles change with hot-plug, reboots and
+//! display settings, so configuration must be keyed by something sturdier.
+//!
+//! **ID Generation Strategy**:
+//! - Primary: monitor device interface path (embeds the EDID manufacturer/product code)
+//! - Fallback: GDI device name plus physical resolution for generic displays
+
+use serde::{Deserialize, Serialize};
+use windows::Win32::Graphics::Gdi::{DISPLAY_DEVICEW, EnumDisplayDevicesW};
+use windows::Win32::UI::WindowsAndMessaging::EDD_GET_DEVICE_INTERFACE_NAME;
+use windows::core::PCWSTR;
+
+use crate::domain::core::Rect;
+
+/// Stable, persistable identifier for a physical monitor
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MonitorId(String);
+
+impl MonitorId {
+    /// Creates a monitor identifier from its string form
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// Returns the identifier as a string slice
+    #[cfg(test)]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for MonitorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Builds the identifier for a monitor from its GDI device name
+///
+/// # Arguments
+/// * `device_name` - Null-terminated `szDevice` from `MONITORINFOEXW` (e.g. `\\.\DISPLAY1`)
+/// * `physical_rect` - Monitor bounds, used by the fallback identifier
+pub fn monitor_id_for_device(device_name: &[u16], physical_rect: Rect) -> MonitorId {
+    let mut device = DISPLAY_DEVICEW {
+        cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+        ..Default::default()
+    };
+
+    // Device 0 of the adapter output is the monitor attached to it
+    let found = unsafe {
+        EnumDisplayDevicesW(
+            PCWSTR::from_raw(device_name.as_ptr()),
+            0,
+            &mut device,
+            EDD_GET_DEVICE_INTERFACE_NAME,
+        )
+        .as_bool()
+    };
+
+    let interface_path = wide_to_string(&device.DeviceID);
+    if found && !interface_path.is_empty() {
+        return MonitorId::new(interface_path);
+    }
+
+    fallback_monitor_id(&wide_to_string(device_name), physical_rect)
+}
+
+/// Builds the fallback identifier used when no device interface path is available
+pub fn fallback_monitor_id(device_name: &str, physical_rect: Rect) -> MonitorId {
+    MonitorId::new(format!(
+        "{}:{}x{}",
+        device_name, physical_rect.w, physical_rect.h
+    ))
+}
+
+/// Converts a null-terminated UTF-16 buffer to a String
+fn wide_to_string(buffer: &[u16]) -> String {
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_id_includes_resolution() {
+        let id = fallback_monitor_id(r"\\.\DISPLAY1", Rect::new(0, 0, 2560, 1440));
+        assert_eq!(id.as_str(), r"\\.\DISPLAY1:2560x1440");
+    }
+
+    #[test]
+    fn wide_string_stops_at_null() {
+        let wide: Vec<u16> = "DISPLAY1\0garbage".encode_utf16().collect();
+        assert_eq!(wide_to_string(&wide), "DISPLAY1");
+    }
+
+    #[test]
+    fn monitor_id_serializes_as_plain_string() {
+        let id = MonitorId::new("MON-A");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"MON-A\"");
+    }
+}
@@ -10,6 +10,8 @@
 //! where secondary monitors can have negative coordinates.
 
 use crate::domain::core::Rect;
+use crate::platform::monitor_id::{MonitorId, monitor_id_for_device};
+use crate::platform::orientation::Orientation;
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
 use windows::Win32::UI::HiDpi::*;
@@ -19,8 +21,10 @@ use windows::Win32::UI::HiDpi::*;
 pub struct Monitor {
     /// Windows handle to the monitor
     pub handle: HMONITOR,
-    /// Zero-based index for stable identification
+    /// Zero-based enumeration index (changes with hot-plug and reboots)
     pub index: usize,
+    /// Stable identifier that survives reconnection and reboots
+    pub id: MonitorId,
     /// Physical rectangle in real pixels (DPI-normalized)
     pub physical_rect: Rect,
     /// Work area in real pixels (excluding taskbar)
@@ -50,6 +54,11 @@ impl Monitor {
         cell_width >= min_cell_width && cell_height >= min_cell_height
     }
 
+    /// Returns the orientation of this monitor's work area
+    pub fn orientation(&self) -> Orientation {
+        Orientation::from_area(self.work_area)
+    }
+
     /// Returns true if this monitor should be rejected due to size constraints
     pub fn should_reject(&self, min_height: i32) -> bool {
         self.work_area.h < min_height
@@ -153,12 +162,15 @@ unsafe extern "system" fn enum_monitor_proc(
             monitor_info.monitorInfo.rcWork.bottom - monitor_info.monitorInfo.rcWork.top,
         );
 
+        let id = monitor_id_for_device(&monitor_info.szDevice, physical_rect);
+
         let is_primary = (monitor_info.monitorInfo.dwFlags & 1) != 0; // MONITORINFOF_PRIMARY = 1
         let dpi_scale = (dpi_x as f32) / 96.0;
 
         let monitor = Monitor {
             handle: hmonitor,
             index: context.next_index,
+            id,
             physical_rect,
             work_area,
             dpi_scale,
@@ -227,6 +239,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(windows)]
     fn can_enumerate_monitors() {
         let result = enumerate_monitors();
         assert!(result.is_ok(), "Should be able to enumerate monitors");
@@ -251,6 +264,7 @@ mod tests {
         let monitor = Monitor {
             handle: HMONITOR(0),
             index: 0,
+            id: MonitorId::new("TEST"),
             physical_rect: Rect::new(0, 0, 1920, 1080),
             work_area: Rect::new(0, 0, 1920, 1040), // 40px taskbar
             dpi_scale: 1.0,
@@ -271,6 +285,7 @@ mod tests {
         let small_monitor = Monitor {
             handle: HMONITOR(0),
             index: 0,
+            id: MonitorId::new("TEST"),
             physical_rect: Rect::new(0, 0, 800, 600),
             work_area: Rect::new(0, 0, 800, 560),
             dpi_scale: 1.0,
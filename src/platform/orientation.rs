@@ -0,0 +1,98 @@
+//! Monitor orientation detection
+//!
+//! Tells whether a monitor is landscape, portrait or (nearly) square based on
+//! its work area. A 10% threshold avoids flip-flopping on near-square displays.
+//! Grid selection per orientation lives in the configuration layer.
+
+use crate::domain::core::Rect;
+
+/// Relative difference between width and height below which a monitor is square
+const SQUARE_THRESHOLD: f32 = 0.10;
+
+/// Physical orientation of a monitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Wider than tall (the common case)
+    Landscape,
+    /// Taller than wide (rotated monitors, tablets)
+    Portrait,
+    /// Width and height within 10% of each other
+    Square,
+}
+
+impl Orientation {
+    /// Detects the orientation of a screen area
+    ///
+    /// # Example
+    /// ```ignore
+    /// let orientation = Orientation::from_area(Rect::new(0, 0, 1080, 1920));
+    /// assert_eq!(orientation, Orientation::Portrait);
+    /// ```
+    pub fn from_area(area: Rect) -> Self {
+        let longest = area.w.max(area.h);
+        if longest <= 0 {
+            return Orientation::Landscape;
+        }
+
+        let difference = (area.w - area.h).abs() as f32 / longest as f32;
+        if difference < SQUARE_THRESHOLD {
+            Orientation::Square
+        } else if area.h > area.w {
+            Orientation::Portrait
+        } else {
+            Orientation::Landscape
+        }
+    }
+}
+
+impl std::fmt::Display for Orientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Orientation::Landscape => write!(f, "landscape"),
+            Orientation::Portrait => write!(f, "portrait"),
+            Orientation::Square => write!(f, "square"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_landscape_and_portrait() {
+        assert_eq!(
+            Orientation::from_area(Rect::new(0, 0, 1920, 1040)),
+            Orientation::Landscape
+        );
+        assert_eq!(
+            Orientation::from_area(Rect::new(1920, 0, 1080, 1880)),
+            Orientation::Portrait
+        );
+    }
+
+    #[test]
+    fn near_square_areas_are_square() {
+        assert_eq!(
+            Orientation::from_area(Rect::new(0, 0, 1920, 1920)),
+            Orientation::Square
+        );
+        // 5% taller than wide is still within the threshold
+        assert_eq!(
+            Orientation::from_area(Rect::new(0, 0, 1000, 1050)),
+            Orientation::Square
+        );
+        assert_eq!(
+            Orientation::from_area(Rect::new(0, 0, 1000, 1200)),
+            Orientation::Portrait
+        );
+    }
+
+    #[test]
+    fn degenerate_area_defaults_to_landscape() {
+        assert_eq!(
+            Orientation::from_area(Rect::new(0, 0, 0, 0)),
+            Orientation::Landscape
+        );
+    }
+}
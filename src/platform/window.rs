@@ -4,13 +4,37 @@
 //! - Getting the currently active window
 //! - Checking if a window is resizable
 //! - Moving and resizing windows to specific rectangles
+//! - Compensating for invisible resize borders (DWM extended frame bounds)
+//! - Respecting application minimum/maximum window sizes
+//! - Moving (without resizing) windows that cannot be resized
+//! - Maximizing, and restoring maximized windows into a target rectangle
+//! - Listing visible top-level windows that can be picked as a target
 //! - Preserving focus during window operations
 //!
 //! CRITICAL: All operations must preserve the active window's focus state
 
+use crate::domain::animation::{self, AnimationOptions};
 use crate::domain::core::Rect;
+use crate::domain::frame::FrameMargins;
+use crate::domain::history::WindowSnapshot;
+use crate::domain::placement::{
+    self, Anchor, PlacementOptions, PlacementOutcome, PlacementStrategy, SizeConstraints,
+    WindowStateAction,
+};
+use crate::platform::windows::{rect_to_win32_rect, win32_rect_to_rect};
+use std::time::Instant;
 use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Dwm::{
+    DWMWA_CLOAKED, DWMWA_EXTENDED_FRAME_BOUNDS, DwmGetWindowAttribute,
+};
+use windows::Win32::Graphics::Gdi::{
+    GetMonitorInfoW, MONITOR_DEFAULTTONEAREST, MONITORINFO, MonitorFromRect, MonitorFromWindow,
+};
+use windows::Win32::System::Threading::{
+    OpenProcess, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION, QueryFullProcessImageNameW,
+};
 use windows::Win32::UI::WindowsAndMessaging::*;
+use windows::core::PWSTR;
 
 /// Error types for window operations
 #[derive(Debug)]
@@ -48,10 +72,18 @@ pub struct WindowInfo {
     pub handle: HWND,
     /// Window title (if available)
     pub title: String,
+    /// Window class name (e.g. "Notepad", "Shell_TrayWnd")
+    pub class_name: String,
+    /// Executable name of the owning process (e.g. "notepad.exe"), empty if unknown
+    pub process_name: String,
     /// Current window rectangle in screen coordinates
     pub rect: Rect,
+    /// Restored (non-maximized) window rectangle in screen coordinates
+    pub normal_rect: Rect,
     /// Whether the window can be resized
     pub is_resizable: bool,
+    /// Whether the window has a title bar
+    pub has_caption: bool,
     /// Whether this is a child window
     pub is_child: bool,
     /// Whether the window is currently maximized
@@ -87,6 +119,12 @@ pub fn get_window_info(hwnd: HWND) -> Result<WindowInfo, WindowError> {
             String::from("<No Title>")
         };
 
+        // Get class and owning process for window rules
+        let mut class_buffer = [0u16; 256];
+        let class_length = GetClassNameW(hwnd, &mut class_buffer);
+        let class_name = String::from_utf16_lossy(&class_buffer[..class_length.max(0) as usize]);
+        let process_name = get_process_name(hwnd).unwrap_or_default();
+
         // Get window rectangle
         let mut window_rect = RECT::default();
         if GetWindowRect(hwnd, &mut window_rect).is_err() {
@@ -103,6 +141,7 @@ pub fn get_window_info(hwnd: HWND) -> Result<WindowInfo, WindowError> {
         // Check if window is resizable by examining its style
         let style = WINDOW_STYLE(GetWindowLongW(hwnd, GWL_STYLE) as u32);
         let is_resizable = (style & WS_THICKFRAME) != WINDOW_STYLE(0);
+        let has_caption = (style & WS_CAPTION) == WS_CAPTION;
 
         // Check if it's a child window
         let is_child = (style & WS_CHILD) != WINDOW_STYLE(0);
@@ -113,70 +152,628 @@ pub fn get_window_info(hwnd: HWND) -> Result<WindowInfo, WindowError> {
             ..Default::default()
         };
 
-        let is_maximized = if GetWindowPlacement(hwnd, &mut placement).is_ok() {
-            placement.showCmd == (SW_SHOWMAXIMIZED.0 as u32)
+        let (is_maximized, normal_rect) = if GetWindowPlacement(hwnd, &mut placement).is_ok() {
+            (
+                placement.showCmd == (SW_SHOWMAXIMIZED.0 as u32),
+                normal_rect_to_screen(hwnd, &placement.rcNormalPosition),
+            )
         } else {
-            false
+            (false, rect)
         };
 
         Ok(WindowInfo {
             handle: hwnd,
             title,
+            class_name,
+            process_name,
             rect,
+            normal_rect,
             is_resizable,
+            has_caption,
             is_child,
             is_maximized,
         })
     }
 }
 
+/// Converts the restored bounds of a WINDOWPLACEMENT into screen coordinates
+///
+/// The bounds are in workspace coordinates of the window's monitor.
+fn normal_rect_to_screen(hwnd: HWND, normal_position: &RECT) -> Rect {
+    let normal_rect = win32_rect_to_rect(normal_position);
+    let mut monitor_info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe {
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+        if GetMonitorInfoW(monitor, &mut monitor_info).as_bool() {
+            placement::from_workspace(
+                normal_rect,
+                win32_rect_to_rect(&monitor_info.rcMonitor),
+                win32_rect_to_rect(&monitor_info.rcWork),
+            )
+        } else {
+            normal_rect
+        }
+    }
+}
+
+/// Gets the executable file name of the process owning a window
+///
+/// # Returns
+/// The file name (e.g. "notepad.exe"), or None if the process cannot be
+/// queried (e.g. elevated processes when running unelevated)
+pub fn get_process_name(hwnd: HWND) -> Option<String> {
+    unsafe {
+        let mut process_id = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+        if process_id == 0 {
+            return None;
+        }
+
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+
+        let mut buffer = [0u16; 1024];
+        let mut length = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut length,
+        );
+        let _ = CloseHandle(process);
+        result.ok()?;
+
+        let path = String::from_utf16_lossy(&buffer[..length as usize]);
+        path.rsplit('\\').next().map(str::to_string)
+    }
+}
+
 /// Moves and resizes a window to the specified rectangle
 ///
 /// This function:
 /// - Preserves the window's Z-order
 /// - Does not change focus
-/// - Returns an error if the window is not resizable
-pub fn position_window(hwnd: HWND, target_rect: Rect) -> Result<(), WindowError> {
+/// - Places the visible frame on the target (invisible borders are compensated)
+/// - Respects the window's min/max track sizes, aligning it with `options.anchor`
+/// - Moves non-resizable windows keeping their size if `options.move_non_resizable`,
+///   otherwise returns an error
+/// - Animates the transition if `options.animation` is enabled
+///
+/// # Returns
+/// How the window was placed relative to the target, or WindowError
+pub fn position_window(
+    hwnd: HWND,
+    target_rect: Rect,
+    options: &PlacementOptions,
+) -> Result<PlacementOutcome, WindowError> {
+    position_window_animated(hwnd, target_rect, options).map(|(outcome, _)| outcome)
+}
+
+/// Moves and resizes a window like `position_window`, reporting slow animations
+///
+/// # Returns
+/// How the window was placed, and whether the animation was cut short
+/// because the window repainted too slowly, or WindowError
+pub fn position_window_animated(
+    hwnd: HWND,
+    target_rect: Rect,
+    options: &PlacementOptions,
+) -> Result<(PlacementOutcome, bool), WindowError> {
+    let mut prepared = prepare_placement(hwnd, target_rect, options)?;
+    let too_slow = animate_window_rect(hwnd, prepared.window_rect(), &options.animation)?;
+    prepared.reanchor()?;
+    Ok((prepared.outcome, too_slow))
+}
+
+/// Geometry of a window placement, computed before the window is moved
+#[derive(Debug, Clone, Copy)]
+struct PreparedPlacement {
+    hwnd: HWND,
+    /// Target area in screen coordinates
+    target: Rect,
+    /// Invisible borders of the window
+    margins: FrameMargins,
+    /// Where the visible frame will be placed
+    outcome: PlacementOutcome,
+    /// Alignment inside the target
+    anchor: Anchor,
+    /// Whether the window is resized (move-only placements keep their size)
+    resizes: bool,
+}
+
+impl PreparedPlacement {
+    /// Returns the window rectangle to apply, including invisible borders
+    fn window_rect(&self) -> Rect {
+        self.margins.expand(self.outcome.rect())
+    }
+
+    /// Re-anchors a resized window at the size it actually took
+    ///
+    /// Some applications enforce limits they don't report.
+    fn reanchor(&mut self) -> Result<(), WindowError> {
+        if !self.resizes {
+            return Ok(());
+        }
+
+        if let Some(actual) = get_window_rect(self.hwnd) {
+            let visible_w = actual.w - self.margins.left - self.margins.right;
+            let visible_h = actual.h - self.margins.top - self.margins.bottom;
+            let placed = self.outcome.rect();
+
+            if (visible_w, visible_h) != (placed.w, placed.h) {
+                let fixed = SizeConstraints::fixed(visible_w, visible_h);
+                self.outcome = placement::fit_to_target(self.target, fixed, self.anchor);
+                set_window_rect(self.hwnd, self.window_rect())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Computes where a window goes without moving it
+///
+/// Maximized windows are restored with the target as their restored bounds.
+fn prepare_placement(
+    hwnd: HWND,
+    target_rect: Rect,
+    options: &PlacementOptions,
+) -> Result<PreparedPlacement, WindowError> {
+    // Validate the window handle
+    if !unsafe { IsWindow(hwnd) }.as_bool() {
+        return Err(WindowError::InvalidHandle(hwnd));
+    }
+
+    // Get window info to check if it's resizable
+    let window_info = get_window_info(hwnd)?;
+    let strategy = placement_strategy(&window_info, options);
+    if strategy == PlacementStrategy::Skip {
+        return Err(WindowError::NotResizable(hwnd));
+    }
+
+    // If window is maximized, restore it with the target as its restored bounds
+    let action = placement::window_state_action(false, window_info.is_maximized, options);
+    if action == WindowStateAction::RestoreToTarget {
+        restore_to_rect(hwnd, target_rect)?;
+    }
+
+    // Constraints and target are expressed in terms of the visible frame
+    let margins = get_frame_margins(hwnd);
+
+    let outcome = if strategy == PlacementStrategy::MoveOnly {
+        let current = get_window_rect(hwnd).unwrap_or(window_info.rect);
+        placement::move_into_target(
+            target_rect,
+            current.w - margins.left - margins.right,
+            current.h - margins.top - margins.bottom,
+            options.anchor,
+        )
+    } else {
+        let constraints = margins.visible_constraints(get_size_constraints(hwnd));
+        placement::fit_to_target(target_rect, constraints, options.anchor)
+    };
+
+    Ok(PreparedPlacement {
+        hwnd,
+        target: target_rect,
+        margins,
+        outcome,
+        anchor: options.anchor,
+        resizes: strategy != PlacementStrategy::MoveOnly,
+    })
+}
+
+/// Restores a maximized window directly into the given rectangle
+///
+/// Updates the restored bounds stored in the window placement, so the window
+/// un-maximizes into the target instead of a size the application remembered,
+/// and later restores go back to the same rectangle.
+pub fn restore_to_rect(hwnd: HWND, target_rect: Rect) -> Result<(), WindowError> {
+    unsafe {
+        let mut placement = WINDOWPLACEMENT {
+            length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
+            ..Default::default()
+        };
+        if GetWindowPlacement(hwnd, &mut placement).is_err() {
+            return Err(WindowError::InfoFailed(hwnd));
+        }
+
+        // WINDOWPLACEMENT uses workspace coordinates of the target monitor
+        let mut monitor_info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        let monitor = MonitorFromRect(&rect_to_win32_rect(&target_rect), MONITOR_DEFAULTTONEAREST);
+        let normal_rect = if GetMonitorInfoW(monitor, &mut monitor_info).as_bool() {
+            placement::to_workspace(
+                target_rect,
+                win32_rect_to_rect(&monitor_info.rcMonitor),
+                win32_rect_to_rect(&monitor_info.rcWork),
+            )
+        } else {
+            target_rect
+        };
+
+        placement.rcNormalPosition = rect_to_win32_rect(&normal_rect);
+        placement.showCmd = SW_RESTORE.0 as u32;
+
+        SetWindowPlacement(hwnd, &placement).map_err(|_| WindowError::PositionFailed(hwnd))
+    }
+}
+
+/// Maximizes a window on the monitor it is currently on
+pub fn maximize_window(hwnd: HWND) -> Result<(), WindowError> {
     unsafe {
-        // Validate the window handle
         if !IsWindow(hwnd).as_bool() {
             return Err(WindowError::InvalidHandle(hwnd));
         }
 
-        // Get window info to check if it's resizable
-        let window_info = get_window_info(hwnd)?;
-        if !window_info.is_resizable {
-            return Err(WindowError::NotResizable(hwnd));
+        // Return value is the previous visibility, not success
+        let _ = ShowWindow(hwnd, SW_MAXIMIZE);
+        Ok(())
+    }
+}
+
+/// Returns a window to previously recorded geometry
+///
+/// Used by placement undo/redo. The rectangle is applied as-is, without frame
+/// compensation, since it was captured with GetWindowRect.
+///
+/// # Arguments
+/// * `hwnd` - Window to restore
+/// * `snapshot` - Recorded geometry to apply. Maximized snapshots get their
+///   restored bounds back before the window is maximized.
+pub fn apply_window_geometry(hwnd: HWND, snapshot: &WindowSnapshot) -> Result<(), WindowError> {
+    let rect = snapshot.rect;
+    if snapshot.maximized {
+        // SW_MAXIMIZE uses the window's current monitor, so restore onto the recorded bounds first
+        restore_to_rect(hwnd, snapshot.normal_rect)?;
+        return maximize_window(hwnd);
+    }
+
+    let window_info = get_window_info(hwnd)?;
+    if window_info.is_maximized {
+        restore_to_rect(hwnd, rect)?;
+    }
+
+    set_window_rect(hwnd, rect)
+}
+
+/// Decides how a window is placed into the selected area
+///
+/// Resizable windows are resized. Non-resizable top-level windows (dialogs,
+/// fixed-size utilities) are moved keeping their size if the options allow it.
+/// Child windows are never placed. `move_only` moves every top-level window.
+pub fn placement_strategy(info: &WindowInfo, options: &PlacementOptions) -> PlacementStrategy {
+    if info.is_child {
+        PlacementStrategy::Skip
+    } else if options.move_only {
+        PlacementStrategy::MoveOnly
+    } else if info.is_resizable {
+        PlacementStrategy::Resize
+    } else if options.move_non_resizable {
+        PlacementStrategy::MoveOnly
+    } else {
+        PlacementStrategy::Skip
+    }
+}
+
+/// Places a window for a grid selection, maximizing it for full-grid selections
+///
+/// # Arguments
+/// * `hwnd` - Window to place
+/// * `target_rect` - Selected area in screen coordinates
+/// * `covers_whole_grid` - Whether the selection spans every grid cell
+/// * `options` - Placement options
+///
+/// # Returns
+/// How the window was placed, and whether it was too slow to animate, or WindowError
+pub fn place_window(
+    hwnd: HWND,
+    target_rect: Rect,
+    covers_whole_grid: bool,
+    options: &PlacementOptions,
+) -> Result<(PlacementOutcome, bool), WindowError> {
+    let window_info = get_window_info(hwnd)?;
+    let action =
+        placement::window_state_action(covers_whole_grid, window_info.is_maximized, options);
+
+    if action == WindowStateAction::Maximize && window_info.is_resizable && !window_info.is_child {
+        // SW_MAXIMIZE uses the window's current monitor, so move it onto the target first
+        restore_to_rect(hwnd, target_rect)?;
+        maximize_window(hwnd)?;
+        return Ok((
+            PlacementOutcome::Maximized {
+                target: target_rect,
+            },
+            false,
+        ));
+    }
+
+    position_window_animated(hwnd, target_rect, options)
+}
+
+/// One window of a multi-window placement
+#[derive(Debug, Clone, Copy)]
+pub struct WindowPlacement {
+    /// Window to place
+    pub hwnd: HWND,
+    /// Target area in screen coordinates
+    pub target: Rect,
+    /// Placement options for this window (after rules)
+    pub options: PlacementOptions,
+    /// Maximize the window once placed, keeping the target as its restored bounds
+    pub maximize: bool,
+}
+
+/// Places several windows in one pass
+///
+/// All windows are moved together with a single deferred window position
+/// update, so they appear in their new places at once. Windows that cannot
+/// be placed do not prevent the others from moving; maximized windows are
+/// restored into their target. Placements are never animated.
+///
+/// # Returns
+/// The outcome for each placement, in the same order
+pub fn place_windows(placements: &[WindowPlacement]) -> Vec<Result<PlacementOutcome, WindowError>> {
+    let mut results: Vec<Result<PreparedPlacement, WindowError>> = placements
+        .iter()
+        .map(|p| prepare_placement(p.hwnd, p.target, &p.options))
+        .collect();
+
+    let moves: Vec<(HWND, Rect)> = results
+        .iter()
+        .flatten()
+        .map(|prepared| (prepared.hwnd, prepared.window_rect()))
+        .collect();
+    if defer_window_rects(&moves).is_err() {
+        // A window closed or refused to move; place the windows one by one
+        for result in &mut results {
+            if let Ok(prepared) = result
+                && let Err(e) = set_window_rect(prepared.hwnd, prepared.window_rect())
+            {
+                *result = Err(e);
+            }
         }
+    }
 
-        // If window is maximized, restore it first
-        if window_info.is_maximized {
-            if !ShowWindow(hwnd, SW_RESTORE).as_bool() {
-                return Err(WindowError::PositionFailed(hwnd));
+    results
+        .into_iter()
+        .zip(placements)
+        .map(|(result, placement)| {
+            let mut prepared = result?;
+            prepared.reanchor()?;
+            if placement.maximize {
+                maximize_window(placement.hwnd)?;
             }
+            Ok(prepared.outcome)
+        })
+        .collect()
+}
+
+/// Moves several windows at once with DeferWindowPos, without changing focus or Z-order
+fn defer_window_rects(moves: &[(HWND, Rect)]) -> Result<(), WindowError> {
+    let Some(&(first, _)) = moves.first() else {
+        return Ok(());
+    };
+
+    unsafe {
+        let mut hdwp = BeginDeferWindowPos(moves.len() as i32)
+            .map_err(|_| WindowError::PositionFailed(first))?;
+        for &(hwnd, rect) in moves {
+            // On failure the whole batch is discarded by the system
+            hdwp = DeferWindowPos(
+                hdwp,
+                hwnd,
+                HWND(0),
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                SWP_NOACTIVATE | SWP_NOZORDER,
+            )
+            .map_err(|_| WindowError::PositionFailed(hwnd))?;
+        }
+        EndDeferWindowPos(hdwp).map_err(|_| WindowError::PositionFailed(first))
+    }
+}
 
-            // Give the window time to restore (avoid race conditions)
-            std::thread::sleep(std::time::Duration::from_millis(50));
+/// Moves a window to a rectangle, animating the transition if enabled
+///
+/// The animation stops early and the window jumps to the target when a
+/// frame takes longer than `options.slow_frame_ms`, which happens with
+/// windows that repaint slowly while being resized.
+///
+/// # Returns
+/// True if the animation was cut short because the window was too slow
+fn animate_window_rect(
+    hwnd: HWND,
+    rect: Rect,
+    options: &AnimationOptions,
+) -> Result<bool, WindowError> {
+    let Some(current) = get_window_rect(hwnd).filter(|_| options.enabled) else {
+        return set_window_rect(hwnd, rect).map(|_| false);
+    };
+
+    let start = Instant::now();
+    for frame in animation::plan_frames(current, rect, options.duration(), options.easing) {
+        if let Some(wait) = frame.at.checked_sub(start.elapsed()) {
+            std::thread::sleep(wait);
+        }
+
+        let frame_start = Instant::now();
+        set_window_rect(hwnd, frame.rect)?;
+        if frame.rect == rect {
+            return Ok(false);
+        }
+        if options.is_too_slow(frame_start.elapsed()) {
+            set_window_rect(hwnd, rect)?;
+            return Ok(true);
         }
+    }
+
+    set_window_rect(hwnd, rect).map(|_| false)
+}
 
-        // Position the window
-        // SWP_NOACTIVATE: Don't activate the window (preserve focus)
-        // SWP_NOZORDER: Don't change Z-order position (HWND parameter ignored)
-        let result = SetWindowPos(
+/// Calls SetWindowPos without changing focus or Z-order
+fn set_window_rect(hwnd: HWND, rect: Rect) -> Result<(), WindowError> {
+    // SWP_NOACTIVATE: Don't activate the window (preserve focus)
+    // SWP_NOZORDER: Don't change Z-order position (HWND parameter ignored)
+    let result = unsafe {
+        SetWindowPos(
             hwnd,
             HWND(0), // Ignored due to SWP_NOZORDER flag
-            target_rect.x,
-            target_rect.y,
-            target_rect.w,
-            target_rect.h,
+            rect.x,
+            rect.y,
+            rect.w,
+            rect.h,
             SWP_NOACTIVATE | SWP_NOZORDER,
+        )
+    };
+
+    result.map_err(|_| WindowError::PositionFailed(hwnd))
+}
+
+/// Returns the current window rectangle, or None if it cannot be read
+fn get_window_rect(hwnd: HWND) -> Option<Rect> {
+    let mut rect = RECT::default();
+    unsafe { GetWindowRect(hwnd, &mut rect) }.ok()?;
+    Some(win32_rect_to_rect(&rect))
+}
+
+/// Queries the minimum and maximum tracking sizes of a window
+///
+/// Starts from the system defaults and lets the window adjust them through
+/// WM_GETMINMAXINFO, exactly as Windows does during an interactive resize.
+/// Hung windows keep the system defaults.
+pub fn get_size_constraints(hwnd: HWND) -> SizeConstraints {
+    unsafe {
+        let mut info = MINMAXINFO {
+            ptMinTrackSize: POINT {
+                x: GetSystemMetrics(SM_CXMINTRACK),
+                y: GetSystemMetrics(SM_CYMINTRACK),
+            },
+            ptMaxTrackSize: POINT {
+                x: GetSystemMetrics(SM_CXMAXTRACK),
+                y: GetSystemMetrics(SM_CYMAXTRACK),
+            },
+            ..Default::default()
+        };
+
+        SendMessageTimeoutW(
+            hwnd,
+            WM_GETMINMAXINFO,
+            WPARAM(0),
+            LPARAM(&mut info as *mut MINMAXINFO as isize),
+            SMTO_ABORTIFHUNG,
+            100,
+            None,
         );
 
-        if result.is_err() {
-            return Err(WindowError::PositionFailed(hwnd));
+        SizeConstraints {
+            min_width: info.ptMinTrackSize.x,
+            min_height: info.ptMinTrackSize.y,
+            max_width: info.ptMaxTrackSize.x,
+            max_height: info.ptMaxTrackSize.y,
         }
+    }
+}
 
-        Ok(())
+/// Measures the invisible borders around a window's visible frame
+///
+/// Uses the DWM extended frame bounds, which exclude the invisible resize
+/// borders and shadows that Windows 10/11 add around most windows.
+///
+/// # Returns
+/// The frame margins, or zero margins if DWM cannot report the frame
+/// (e.g. composition disabled or a borderless window)
+pub fn get_frame_margins(hwnd: HWND) -> FrameMargins {
+    unsafe {
+        let Some(window_rect) = get_window_rect(hwnd) else {
+            return FrameMargins::default();
+        };
+
+        let mut frame_rect = RECT::default();
+        if DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_EXTENDED_FRAME_BOUNDS,
+            &mut frame_rect as *mut RECT as *mut std::ffi::c_void,
+            std::mem::size_of::<RECT>() as u32,
+        )
+        .is_err()
+        {
+            return FrameMargins::default();
+        }
+
+        FrameMargins::between(window_rect, win32_rect_to_rect(&frame_rect))
+    }
+}
+
+/// Lists the visible top-level windows that can be positioned
+///
+/// Windows are returned in z-order, topmost first. Minimized windows, windows
+/// hidden by DWM (e.g. suspended UWP apps or other virtual desktops) and
+/// windows rejected by `is_window_suitable_for_positioning` are skipped.
+pub fn enumerate_candidate_windows() -> Vec<WindowInfo> {
+    let mut handles: Vec<HWND> = Vec::new();
+    unsafe {
+        // A failed enumeration leaves whatever was collected so far
+        let _ = EnumWindows(
+            Some(enum_window_proc),
+            LPARAM(&mut handles as *mut _ as isize),
+        );
+    }
+
+    handles
+        .into_iter()
+        .filter(|&hwnd| unsafe { IsWindowVisible(hwnd).as_bool() && !IsIconic(hwnd).as_bool() })
+        .filter(|&hwnd| !is_cloaked(hwnd))
+        .filter(|&hwnd| is_window_suitable_for_positioning(hwnd))
+        .filter_map(|hwnd| get_window_info(hwnd).ok())
+        .collect()
+}
+
+/// Callback for EnumWindows collecting every top-level window handle
+unsafe extern "system" fn enum_window_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    unsafe {
+        let handles = &mut *(lparam.0 as *mut Vec<HWND>);
+        handles.push(hwnd);
+    }
+    TRUE
+}
+
+/// Returns true if DWM hides the window although it is marked visible
+fn is_cloaked(hwnd: HWND) -> bool {
+    let mut cloaked = 0u32;
+    unsafe {
+        DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_CLOAKED,
+            &mut cloaked as *mut u32 as *mut std::ffi::c_void,
+            std::mem::size_of::<u32>() as u32,
+        )
+        .is_ok()
+            && cloaked != 0
+    }
+}
+
+/// Brings a window to the top of the z-order without activating it
+///
+/// Used to show which window is picked as the target while the overlay
+/// (which stays topmost) is open.
+pub fn raise_window(hwnd: HWND) -> Result<(), WindowError> {
+    unsafe {
+        SetWindowPos(
+            hwnd,
+            HWND_TOP,
+            0,
+            0,
+            0,
+            0,
+            SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+        )
+        .map_err(|_| WindowError::PositionFailed(hwnd))
     }
 }
 
@@ -184,9 +781,12 @@ pub fn position_window(hwnd: HWND, target_rect: Rect) -> Result<(), WindowError>
 ///
 /// This is a convenience function that combines getting the active window
 /// and positioning it in one operation.
-pub fn position_active_window(target_rect: Rect) -> Result<(), WindowError> {
+pub fn position_active_window(
+    target_rect: Rect,
+    options: &PlacementOptions,
+) -> Result<PlacementOutcome, WindowError> {
     let window_info = get_active_window()?;
-    position_window(window_info.handle, target_rect)
+    position_window(window_info.handle, target_rect, options)
 }
 
 /// Checks if the specified window is suitable for grid positioning
@@ -207,6 +807,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(windows)]
     fn can_get_active_window() {
         // This test will only pass if there's actually an active window
         // In a real environment, there should always be at least the test runner window
@@ -222,7 +823,81 @@ mod tests {
         }
     }
 
+    fn fake_window(is_resizable: bool, is_child: bool) -> WindowInfo {
+        WindowInfo {
+            handle: HWND(0),
+            title: String::from("Fake"),
+            class_name: String::from("FakeClass"),
+            process_name: String::from("fake.exe"),
+            rect: Rect::new(100, 100, 400, 300),
+            normal_rect: Rect::new(100, 100, 400, 300),
+            is_resizable,
+            has_caption: true,
+            is_child,
+            is_maximized: false,
+        }
+    }
+
+    #[test]
+    fn resizable_windows_are_resized() {
+        let options = PlacementOptions::default();
+        assert_eq!(
+            placement_strategy(&fake_window(true, false), &options),
+            PlacementStrategy::Resize
+        );
+    }
+
+    #[test]
+    fn non_resizable_windows_follow_options() {
+        let dialog = fake_window(false, false);
+
+        let options = PlacementOptions::default();
+        assert_eq!(
+            placement_strategy(&dialog, &options),
+            PlacementStrategy::MoveOnly
+        );
+
+        let options = PlacementOptions {
+            move_non_resizable: false,
+            ..PlacementOptions::default()
+        };
+        assert_eq!(
+            placement_strategy(&dialog, &options),
+            PlacementStrategy::Skip
+        );
+    }
+
+    #[test]
+    fn move_only_keeps_resizable_windows_size() {
+        let options = PlacementOptions {
+            move_only: true,
+            ..PlacementOptions::default()
+        };
+        assert_eq!(
+            placement_strategy(&fake_window(true, false), &options),
+            PlacementStrategy::MoveOnly
+        );
+        assert_eq!(
+            placement_strategy(&fake_window(true, true), &options),
+            PlacementStrategy::Skip
+        );
+    }
+
+    #[test]
+    fn child_windows_are_skipped() {
+        let options = PlacementOptions::default();
+        assert_eq!(
+            placement_strategy(&fake_window(true, true), &options),
+            PlacementStrategy::Skip
+        );
+        assert_eq!(
+            placement_strategy(&fake_window(false, true), &options),
+            PlacementStrategy::Skip
+        );
+    }
+
     #[test]
+    #[cfg(windows)]
     fn window_info_validation() {
         // Test with invalid handle
         let invalid_hwnd = HWND(999999);
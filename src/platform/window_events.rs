@@ -0,0 +1,151 @@
+//! Window creation and removal events
+//!
+//! Reports top-level windows as they appear and disappear so they can be
+//! placed or re-tiled automatically. The `WindowEventSource` trait decouples
+//! consumers from the Win32 event hook so they can be tested with a fake
+//! source.
+
+use std::sync::Mutex;
+
+use crate::platform::window::{self, WindowInfo};
+use windows::Win32::Foundation::HWND;
+use windows::Win32::UI::Accessibility::{HWINEVENTHOOK, SetWinEventHook, UnhookWinEvent};
+use windows::Win32::UI::WindowsAndMessaging::{
+    CHILDID_SELF, EVENT_OBJECT_DESTROY, EVENT_OBJECT_HIDE, EVENT_OBJECT_SHOW, GA_ROOT, GetAncestor,
+    OBJID_WINDOW, WINEVENT_OUTOFCONTEXT, WINEVENT_SKIPOWNPROCESS,
+};
+
+/// Source of top-level windows being shown and closed
+pub trait WindowEventSource {
+    /// Returns the raw handles of windows shown since the last call
+    fn poll_shown(&mut self) -> Vec<isize>;
+
+    /// Returns the raw handles of windows hidden or destroyed since the last call
+    fn poll_closed(&mut self) -> Vec<isize>;
+
+    /// Returns the raw handles of windows destroyed since the last call
+    ///
+    /// Unlike hidden windows, destroyed windows are gone for good and their
+    /// handles may be reused by new windows.
+    fn poll_destroyed(&mut self) -> Vec<isize>;
+
+    /// Returns current information about a window, or None if it is gone
+    fn window_info(&self, handle: isize) -> Option<WindowInfo>;
+}
+
+/// Handles reported by the event hook, drained by `poll_shown`
+///
+/// Out-of-context WinEvent callbacks run on the thread that installed the
+/// hook while it processes messages, so the lock is never contended.
+static SHOWN_WINDOWS: Mutex<Vec<isize>> = Mutex::new(Vec::new());
+
+/// Handles reported as hidden or destroyed, drained by `poll_closed`
+static CLOSED_WINDOWS: Mutex<Vec<isize>> = Mutex::new(Vec::new());
+
+/// Handles reported as destroyed, drained by `poll_destroyed`
+static DESTROYED_WINDOWS: Mutex<Vec<isize>> = Mutex::new(Vec::new());
+
+/// Callback for EVENT_OBJECT_DESTROY, EVENT_OBJECT_SHOW and EVENT_OBJECT_HIDE
+unsafe extern "system" fn win_event_proc(
+    _hook: HWINEVENTHOOK,
+    event: u32,
+    hwnd: HWND,
+    id_object: i32,
+    id_child: i32,
+    _event_thread: u32,
+    _event_time: u32,
+) {
+    if hwnd.0 == 0 || id_object != OBJID_WINDOW.0 || id_child != CHILDID_SELF as i32 {
+        return;
+    }
+
+    // A destroyed window has no ancestors left to check, so closed handles
+    // are reported as they are and consumers ignore the ones they don't know
+    if event != EVENT_OBJECT_SHOW {
+        if let Ok(mut closed) = CLOSED_WINDOWS.lock() {
+            closed.push(hwnd.0);
+        }
+        if event == EVENT_OBJECT_DESTROY
+            && let Ok(mut destroyed) = DESTROYED_WINDOWS.lock()
+        {
+            destroyed.push(hwnd.0);
+        }
+        return;
+    }
+
+    if unsafe { GetAncestor(hwnd, GA_ROOT) } != hwnd {
+        return;
+    }
+
+    if let Ok(mut shown) = SHOWN_WINDOWS.lock() {
+        shown.push(hwnd.0);
+    }
+}
+
+/// Window event source backed by a WinEvent hook
+///
+/// The hook is removed when the source is dropped.
+pub struct WinEventSource {
+    hook: HWINEVENTHOOK,
+}
+
+impl WinEventSource {
+    /// Installs the window event hook
+    ///
+    /// # Returns
+    /// The event source, or None if the hook could not be installed
+    pub fn install() -> Option<Self> {
+        let hook = unsafe {
+            SetWinEventHook(
+                EVENT_OBJECT_DESTROY,
+                EVENT_OBJECT_HIDE,
+                None,
+                Some(win_event_proc),
+                0,
+                0,
+                WINEVENT_OUTOFCONTEXT | WINEVENT_SKIPOWNPROCESS,
+            )
+        };
+
+        if hook.is_invalid() {
+            None
+        } else {
+            Some(Self { hook })
+        }
+    }
+}
+
+impl WindowEventSource for WinEventSource {
+    fn poll_shown(&mut self) -> Vec<isize> {
+        SHOWN_WINDOWS
+            .lock()
+            .map(|mut shown| std::mem::take(&mut *shown))
+            .unwrap_or_default()
+    }
+
+    fn poll_closed(&mut self) -> Vec<isize> {
+        CLOSED_WINDOWS
+            .lock()
+            .map(|mut closed| std::mem::take(&mut *closed))
+            .unwrap_or_default()
+    }
+
+    fn poll_destroyed(&mut self) -> Vec<isize> {
+        DESTROYED_WINDOWS
+            .lock()
+            .map(|mut destroyed| std::mem::take(&mut *destroyed))
+            .unwrap_or_default()
+    }
+
+    fn window_info(&self, handle: isize) -> Option<WindowInfo> {
+        window::get_window_info(HWND(handle)).ok()
+    }
+}
+
+impl Drop for WinEventSource {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = UnhookWinEvent(self.hook);
+        }
+    }
+}
@@ -1,4 +1,8 @@
+#[cfg(windows)]
 pub mod overlay;
+pub mod preview;
 pub mod renderer;
+pub mod theme;
 
+#[cfg(windows)]
 pub use overlay::{OverlayError, OverlayManager};
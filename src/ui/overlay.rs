@@ -11,25 +11,24 @@ use std::sync::{Arc, Mutex};
 use windows::core::Error;
 use windows::Win32::Foundation::{COLORREF, HWND, LPARAM, LRESULT, POINT, WPARAM};
 use windows::Win32::Graphics::Gdi::{
-    AC_SRC_ALPHA, AC_SRC_OVER, BI_RGB, BITMAPINFO, BITMAPINFOHEADER, BLENDFUNCTION, BeginPaint,
-    CLIP_DEFAULT_PRECIS, CreateCompatibleDC, CreateDIBSection, CreateFontW, CreateSolidBrush,
-    DEFAULT_CHARSET, DEFAULT_PITCH, DEFAULT_QUALITY, DIB_RGB_COLORS, DeleteDC, DeleteObject,
-    EndPaint, FF_DONTCARE, FW_BOLD, GetDC, HGDIOBJ, InvalidateRect, OUT_DEFAULT_PRECIS,
-    PAINTSTRUCT, ReleaseDC, SelectObject, SetBkMode, SetTextColor, TRANSPARENT, TextOutW,
+    AC_SRC_ALPHA, AC_SRC_OVER, BI_RGB, BITMAPINFO, BITMAPINFOHEADER, BLENDFUNCTION,
+    CreateCompatibleDC, CreateDIBSection, DIB_RGB_COLORS, DeleteDC, DeleteObject, GetDC, HGDIOBJ,
+    ReleaseDC, SelectObject,
 };
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::UI::WindowsAndMessaging::{
-    CreateWindowExW, DefWindowProcW, DestroyWindow, GWLP_USERDATA, LWA_ALPHA, RegisterClassW,
-    SW_HIDE, SW_SHOW, SetLayeredWindowAttributes, SetWindowLongPtrW, ShowWindow, ULW_ALPHA,
-    UpdateLayeredWindow, WM_DESTROY, WM_PAINT, WNDCLASSW, WS_EX_LAYERED, WS_EX_NOACTIVATE,
+    CreateWindowExW, DefWindowProcW, DestroyWindow, RegisterClassW, SW_HIDE, SW_SHOW, ShowWindow,
+    ULW_ALPHA, UpdateLayeredWindow, WM_DESTROY, WNDCLASSW, WS_EX_LAYERED, WS_EX_NOACTIVATE,
     WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
 };
 use windows::core::w;
 
 use crate::domain::core::Rect;
 use crate::domain::grid::Grid;
+use crate::domain::keyboard::GridCoords;
 use crate::platform::monitors::Monitor;
-use crate::ui::renderer::{GridLayout, GridRenderer, RendererError};
+use crate::ui::renderer::{GridLayout, GridRenderer, RendererError, premultiplied_bgra};
+use crate::ui::theme::Theme;
 
 /// Overlay management errors
 #[derive(Debug, thiserror::Error)]
@@ -40,9 +39,6 @@ pub enum OverlayError {
     #[error("Failed to create overlay window for monitor {monitor_index}")]
     WindowCreationFailed { monitor_index: usize },
 
-    #[error("Failed to configure overlay transparency")]
-    TransparencyConfigurationFailed,
-
     #[error("Failed to acquire screen device context")]
     DeviceContextFailed,
 
@@ -89,6 +85,15 @@ pub struct OverlayWindow {
     /// Whether this overlay is the active monitor (shows letters)
     pub is_active: bool,
 
+    /// Colors and sizes used to draw the grid
+    pub theme: Theme,
+
+    /// Highlighted cells (first and last corner), if a selection is shown here
+    selection: Option<(GridCoords, GridCoords)>,
+
+    /// Whether the highlighted selection was rejected
+    selection_rejected: bool,
+
     /// Cached rendered content
     cached_pixmap: Option<tiny_skia::Pixmap>,
 
@@ -98,7 +103,12 @@ pub struct OverlayWindow {
 
 impl OverlayWindow {
     /// Create a new overlay window for the specified monitor
-    fn new(monitor_index: usize, monitor: &Monitor, grid: Grid) -> Result<Self, OverlayError> {
+    fn new(
+        monitor_index: usize,
+        monitor: &Monitor,
+        grid: Grid,
+        theme: Theme,
+    ) -> Result<Self, OverlayError> {
         let class_name = w!("TactileWinOverlayWindow");
 
         // Register window class if needed
@@ -107,10 +117,7 @@ impl OverlayWindow {
         // Create the overlay window
         let hwnd = Self::create_overlay_window(class_name, &monitor.work_area)?;
 
-        // Configure transparency
-        Self::configure_transparency(hwnd)?;
-
-        let overlay = Self {
+        Ok(Self {
             hwnd,
             monitor_index,
             monitor_rect: monitor.work_area,
@@ -118,20 +125,19 @@ impl OverlayWindow {
             dpi_scale: monitor.dpi_scale,
             visible: false,
             is_active: false,
+            theme,
+            selection: None,
+            selection_rejected: false,
             cached_pixmap: None,
             renderer: GridRenderer::new(),
-        };
-
-        // Store pointer to self in window user data for access from WM_PAINT
-        unsafe {
-            use windows::Win32::UI::WindowsAndMessaging::{GWLP_USERDATA, SetWindowLongPtrW};
-            SetWindowLongPtrW(hwnd, GWLP_USERDATA, &overlay as *const _ as isize);
-        }
-
-        Ok(overlay)
+        })
     }
 
     /// Register overlay window class
+    ///
+    /// The class does no painting of its own: layered windows updated with
+    /// UpdateLayeredWindow are composed from the pixmap rendered by
+    /// `GridRenderer`, so there is a single rendering pipeline.
     fn register_window_class(class_name: windows::core::PCWSTR) -> Result<(), OverlayError> {
         // Window procedure for overlay windows
         unsafe extern "system" fn overlay_window_proc(
@@ -141,105 +147,6 @@ impl OverlayWindow {
             lparam: LPARAM,
         ) -> LRESULT {
             match msg {
-                WM_PAINT => {
-                    use windows::Win32::UI::WindowsAndMessaging::{
-                        GWLP_USERDATA, GetWindowLongPtrW,
-                    };
-
-                    unsafe {
-                        let mut ps = PAINTSTRUCT::default();
-                        let hdc = BeginPaint(hwnd, &mut ps);
-
-                        // Try to get the overlay window pointer from user data
-                        let overlay_ptr = GetWindowLongPtrW(hwnd, GWLP_USERDATA);
-
-                        if overlay_ptr != 0 {
-                            let overlay = &*(overlay_ptr as *const OverlayWindow);
-
-                            // Set up drawing context
-                            SetBkMode(hdc, TRANSPARENT);
-                            SetTextColor(hdc, COLORREF(0x0000ffff)); // Yellow text
-
-                            // Create a thicker white pen for visible grid lines
-                            use windows::Win32::Graphics::Gdi::{CreatePen, PS_SOLID};
-                            let hpen = CreatePen(PS_SOLID, 3, COLORREF(0x00ffffff)); // 3 pixels thick, white
-                            let old_pen = SelectObject(hdc, HGDIOBJ(hpen.0));
-
-                            // Create font for grid letters
-                            let (cell_width, cell_height) = overlay.grid.cell_size();
-                            let font_name: Vec<u16> = "Arial\0".encode_utf16().collect();
-                            let font_height = (cell_height / 2) as i32; // Half cell height
-                            let hfont = CreateFontW(
-                                font_height,
-                                0,
-                                0,
-                                0,
-                                FW_BOLD.0 as i32,
-                                0,
-                                0,
-                                0,
-                                DEFAULT_CHARSET.0 as u32,
-                                OUT_DEFAULT_PRECIS.0 as u32,
-                                CLIP_DEFAULT_PRECIS.0 as u32,
-                                DEFAULT_QUALITY.0 as u32,
-                                (DEFAULT_PITCH.0 | FF_DONTCARE.0) as u32,
-                                windows::core::PCWSTR(font_name.as_ptr()),
-                            );
-                            let old_font = SelectObject(hdc, HGDIOBJ(hfont.0));
-
-                            // Draw vertical grid lines
-                            let (rows, cols) = overlay.grid.dimensions();
-                            let cell_width = cell_width as i32;
-                            let cell_height = cell_height as i32;
-                            use windows::Win32::Graphics::Gdi::{LineTo, MoveToEx};
-
-                            for col in 0..=cols {
-                                let x = (col as i32) * cell_width;
-                                MoveToEx(hdc, x, 0, None);
-                                LineTo(hdc, x, (rows as i32) * cell_height);
-                            }
-
-                            // Draw horizontal grid lines
-                            for row in 0..=rows {
-                                let y = (row as i32) * cell_height;
-                                MoveToEx(hdc, 0, y, None);
-                                LineTo(hdc, (cols as i32) * cell_width, y);
-                            }
-
-                            // Draw letters in cells (if active monitor)
-                            if overlay.is_active {
-                                use crate::domain::keyboard::GridCoords;
-                                let layout = overlay.grid.keyboard_layout();
-                                for row in 0..rows {
-                                    for col in 0..cols {
-                                        let coords = GridCoords::new(row, col);
-                                        if let Ok(key) = layout.coords_to_key(coords) {
-                                            let center_x = (col as i32) * cell_width
-                                                + cell_width / 2
-                                                - font_height / 3;
-                                            let center_y = (row as i32) * cell_height
-                                                + cell_height / 2
-                                                - font_height / 3;
-
-                                            let text: Vec<u16> =
-                                                format!("{}\0", key).encode_utf16().collect();
-                                            TextOutW(hdc, center_x, center_y, &text);
-                                        }
-                                    }
-                                }
-                            }
-
-                            // Restore and cleanup
-                            SelectObject(hdc, old_font);
-                            DeleteObject(HGDIOBJ(hfont.0));
-                            SelectObject(hdc, old_pen);
-                            DeleteObject(HGDIOBJ(hpen.0));
-                        }
-
-                        EndPaint(hwnd, &ps);
-                    }
-                    LRESULT(0)
-                }
                 WM_DESTROY => LRESULT(0),
                 _ => unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) },
             }
@@ -247,14 +154,10 @@ impl OverlayWindow {
 
         let hinstance = unsafe { GetModuleHandleW(None).unwrap() };
 
-        // Create a dark blue brush for the background (will be semi-transparent)
-        let hbrush = unsafe { CreateSolidBrush(COLORREF(0x00330000)) }; // Dark blue: BGR format
-
         let wc = WNDCLASSW {
             lpfnWndProc: Some(overlay_window_proc),
             hInstance: hinstance.into(),
             lpszClassName: class_name,
-            hbrBackground: hbrush,
             ..Default::default()
         };
 
@@ -302,34 +205,20 @@ impl OverlayWindow {
         Ok(hwnd)
     }
 
-    /// Configure window transparency and alpha blending
-    fn configure_transparency(hwnd: HWND) -> Result<(), OverlayError> {
-        // Set semi-transparent overlay (no color keying - we want to see the background)
-        let result = unsafe {
-            SetLayeredWindowAttributes(
-                hwnd,
-                COLORREF(0), // Not used when only LWA_ALPHA is set
-                180,         // Alpha value (0-255, 180 = ~70% opaque for visibility)
-                LWA_ALPHA,   // Only alpha blending, no color key
-            )
-        };
-
-        if result.is_err() {
-            return Err(OverlayError::TransparencyConfigurationFailed);
-        }
-
-        Ok(())
-    }
-
     /// Show the overlay window
+    ///
+    /// The grid is rendered first: a layered window shows nothing until
+    /// its content has been set with UpdateLayeredWindow.
     pub fn show(&mut self) {
         if !self.visible {
+            if let Err(err) = self.render_grid() {
+                eprintln!(
+                    "Overlay rendering failed on monitor {}: {}",
+                    self.monitor_index, err
+                );
+            }
             unsafe {
-                // Update window user data pointer before showing
-                SetWindowLongPtrW(self.hwnd, GWLP_USERDATA, self as *const _ as isize);
                 ShowWindow(self.hwnd, SW_SHOW);
-                // Trigger initial paint
-                InvalidateRect(self.hwnd, None, false);
             }
             self.visible = true;
         }
@@ -351,21 +240,12 @@ impl OverlayWindow {
     }
 
     /// Set whether this overlay is the active monitor
+    ///
+    /// The change becomes visible with the next `render_grid`.
     pub fn set_active(&mut self, active: bool) {
         if self.is_active != active {
             self.is_active = active;
             self.cached_pixmap = None;
-
-            // Update window user data pointer (in case self moved in memory)
-            unsafe {
-                SetWindowLongPtrW(self.hwnd, GWLP_USERDATA, self as *const _ as isize);
-            }
-
-            // Trigger repaint to show/hide letters
-            unsafe {
-                use windows::Win32::Graphics::Gdi::InvalidateRect;
-                InvalidateRect(self.hwnd, None, false);
-            }
         }
     }
 
@@ -374,15 +254,41 @@ impl OverlayWindow {
         self.is_active
     }
 
+    /// Highlights the cells from `start` to `end`, or removes the highlight
+    ///
+    /// The change becomes visible with the next `render_grid`.
+    pub fn set_selection(&mut self, selection: Option<(GridCoords, GridCoords)>) {
+        self.selection = selection;
+        self.selection_rejected = false;
+        self.cached_pixmap = None;
+    }
+
+    /// Shows the highlighted selection in the theme's error color
+    ///
+    /// The change becomes visible with the next `render_grid`.
+    pub fn reject_selection(&mut self) {
+        if self.selection.is_some() {
+            self.selection_rejected = true;
+            self.cached_pixmap = None;
+        }
+    }
+
     /// Render the grid content
     pub fn render_grid(&mut self) -> Result<(), OverlayError> {
         // Create grid layout
-        let layout = GridLayout::from_grid(
+        let mut layout = GridLayout::from_grid(
             &self.grid,
             self.monitor_rect,
             self.is_active,
             self.dpi_scale,
+            &self.theme,
         );
+        if let Some((start, end)) = self.selection {
+            layout.set_selection(&self.grid, start, end);
+            if self.selection_rejected {
+                layout.mark_selection_rejected();
+            }
+        }
 
         // Render to pixmap
         let pixmap = self.renderer.render_layout(&layout)?;
@@ -455,8 +361,9 @@ impl OverlayWindow {
             }
 
             {
-                let dst = slice::from_raw_parts_mut(pixel_ptr as *mut u8, pixmap.data().len());
-                dst.copy_from_slice(pixmap.data());
+                let bgra = premultiplied_bgra(pixmap);
+                let dst = slice::from_raw_parts_mut(pixel_ptr as *mut u8, bgra.len());
+                dst.copy_from_slice(&bgra);
             }
 
             let old_bitmap = SelectObject(memory_dc, dib_object);
@@ -524,6 +431,9 @@ pub struct OverlayManager {
 
     /// Current visibility state
     visible: bool,
+
+    /// Theme given to every overlay window
+    theme: Theme,
 }
 
 impl OverlayManager {
@@ -532,6 +442,18 @@ impl OverlayManager {
         Self {
             overlays: Arc::new(Mutex::new(HashMap::new())),
             visible: false,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Sets the theme for all overlays, including ones created later
+    ///
+    /// Takes effect on the next render.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        let mut overlays = self.overlays.lock().unwrap();
+        for overlay in overlays.values_mut() {
+            overlay.theme = theme;
         }
     }
 
@@ -548,7 +470,7 @@ impl OverlayManager {
 
         // Create overlay for each monitor with its corresponding grid
         for (index, (monitor, grid)) in monitors.iter().zip(grids.iter()).enumerate() {
-            let overlay = OverlayWindow::new(index, monitor, grid.clone())?;
+            let overlay = OverlayWindow::new(index, monitor, grid.clone(), self.theme)?;
             overlays.insert(index, overlay);
         }
 
@@ -567,11 +489,14 @@ impl OverlayManager {
     }
 
     /// Hide overlays on all monitors
+    ///
+    /// Highlighted selections are removed so the next show starts clean.
     pub fn hide_all(&mut self) {
         if self.visible {
             let mut overlays = self.overlays.lock().unwrap();
             for overlay in overlays.values_mut() {
                 overlay.hide();
+                overlay.set_selection(None);
             }
             self.visible = false;
         }
@@ -634,6 +559,33 @@ impl OverlayManager {
         None
     }
 
+    /// Highlights a selection on one monitor and clears it on the others
+    ///
+    /// # Arguments
+    /// * `monitor_index` - Monitor whose grid the cells belong to
+    /// * `start` - First corner of the selection
+    /// * `end` - Last corner of the selection (equal to `start` for one cell)
+    pub fn set_selection(&mut self, monitor_index: usize, start: GridCoords, end: GridCoords) {
+        {
+            let mut overlays = self.overlays.lock().unwrap();
+            for (index, overlay) in overlays.iter_mut() {
+                let selection = (*index == monitor_index).then_some((start, end));
+                overlay.set_selection(selection);
+            }
+        } // Release the mutex lock here
+
+        self.render_all_grids();
+    }
+
+    /// Shows the highlighted selection on a monitor as rejected
+    pub fn reject_selection(&mut self, monitor_index: usize) {
+        if let Some(overlay) = self.overlays.lock().unwrap().get_mut(&monitor_index) {
+            overlay.reject_selection();
+        }
+
+        self.render_all_grids();
+    }
+
     /// Render grid content for all overlays
     pub fn render_all_grids(&mut self) {
         let mut overlays = self.overlays.lock().unwrap();
@@ -695,6 +647,7 @@ mod tests {
         // Create mock monitors
         let monitors = vec![Monitor {
             index: 0,
+            id: crate::platform::monitor_id::MonitorId::new("TEST"),
             handle: windows::Win32::Graphics::Gdi::HMONITOR(1),
             work_area: Rect {
                 x: 0,
@@ -0,0 +1,354 @@
+//! Headless overlay previews
+//!
+//! Renders the grid overlay exactly as it appears on screen, but into a PNG
+//! file instead of a window. Nothing here touches Win32, so previews and
+//! golden-image tests work on any platform.
+//!
+//! Usage: `tactile-win render --width 2560 --height 1440 --layout 4x2 --active --selection Q-S out.png`
+
+use std::path::PathBuf;
+
+use tiny_skia::Pixmap;
+
+use crate::config::settings::{GridConfig, SettingsError};
+use crate::config::window_rules::{KeyRange, KeyRangeError};
+use crate::domain::core::Rect;
+use crate::domain::grid::{Grid, GridError};
+use crate::ui::renderer::{GridLayout, GridRenderer, RendererError};
+use crate::ui::theme::{BUILTIN_THEMES, Theme};
+
+/// Command-line usage of the render mode
+pub const USAGE: &str = "Usage: tactile-win render [--width PX] [--height PX] [--layout COLSxROWS] \
+[--active] [--selection KEY-KEY] [--dpi-scale FACTOR] [--theme NAME] OUTPUT.png";
+
+/// Errors that can occur while rendering a preview
+#[derive(Debug, thiserror::Error)]
+pub enum PreviewError {
+    #[error("{0}\n{USAGE}")]
+    Usage(String),
+
+    #[error(transparent)]
+    Layout(#[from] SettingsError),
+
+    #[error(transparent)]
+    Selection(#[from] KeyRangeError),
+
+    #[error("Invalid grid: {0:?}")]
+    Grid(GridError),
+
+    #[error("Rendering failed: {0}")]
+    Renderer(#[from] RendererError),
+
+    #[error("Failed to write preview: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl From<GridError> for PreviewError {
+    fn from(error: GridError) -> Self {
+        Self::Grid(error)
+    }
+}
+
+/// What to render and where to write it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewOptions {
+    pub width: u32,
+    pub height: u32,
+    pub layout: GridConfig,
+    /// Render the overlay as the active monitor's
+    pub active: bool,
+    /// Cells to highlight as selected
+    pub selection: Option<KeyRange>,
+    pub dpi_scale: f32,
+    /// Built-in theme to draw with
+    pub theme: Theme,
+    pub output: PathBuf,
+}
+
+impl PreviewOptions {
+    /// Creates options for a 1920x1080 preview of the default grid
+    pub fn new(output: impl Into<PathBuf>) -> Self {
+        Self {
+            width: 1920,
+            height: 1080,
+            layout: GridConfig::default(),
+            active: false,
+            selection: None,
+            dpi_scale: 1.0,
+            theme: Theme::default(),
+            output: output.into(),
+        }
+    }
+}
+
+/// Parses the arguments following `render`
+///
+/// # Arguments
+/// * `args` - Command-line arguments, without the program name and `render`
+pub fn parse_args(args: &[String]) -> Result<PreviewOptions, PreviewError> {
+    let mut output = None;
+    let mut options = PreviewOptions::new(PathBuf::new());
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| PreviewError::Usage(format!("Missing value for {}", arg)))
+        };
+
+        match arg.as_str() {
+            "--width" => options.width = parse_number(arg, value()?)?,
+            "--height" => options.height = parse_number(arg, value()?)?,
+            "--dpi-scale" => options.dpi_scale = parse_number(arg, value()?)?,
+            "--layout" => options.layout = value()?.parse()?,
+            "--selection" => options.selection = Some(value()?.parse()?),
+            "--theme" => {
+                let name = value()?;
+                options.theme = Theme::builtin(name).ok_or_else(|| {
+                    PreviewError::Usage(format!(
+                        "Unknown theme '{}' (built-in themes: {})",
+                        name,
+                        BUILTIN_THEMES.join(", ")
+                    ))
+                })?;
+            }
+            "--active" => options.active = true,
+            flag if flag.starts_with("--") => {
+                return Err(PreviewError::Usage(format!("Unknown option {}", flag)));
+            }
+            path if output.is_none() => output = Some(PathBuf::from(path)),
+            extra => {
+                return Err(PreviewError::Usage(format!(
+                    "Unexpected argument {}",
+                    extra
+                )));
+            }
+        }
+    }
+
+    options.output = output.ok_or_else(|| PreviewError::Usage("Missing output file".into()))?;
+    Ok(options)
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, PreviewError> {
+    value
+        .parse()
+        .map_err(|_| PreviewError::Usage(format!("Invalid value '{}' for {}", value, flag)))
+}
+
+/// Renders the overlay described by `options`
+///
+/// Uses the same layout and renderer as the on-screen overlay, so the result
+/// matches what users see on a monitor of the given size.
+pub fn render_preview(options: &PreviewOptions) -> Result<Pixmap, PreviewError> {
+    let area = Rect::new(0, 0, options.width as i32, options.height as i32);
+    let grid = Grid::new(options.layout.rows, options.layout.cols, area)?;
+    let mut layout = GridLayout::from_grid(
+        &grid,
+        area,
+        options.active,
+        options.dpi_scale,
+        &options.theme,
+    );
+
+    if let Some(range) = options.selection {
+        let start = grid.key_to_coords(range.start)?;
+        let end = grid.key_to_coords(range.end)?;
+        layout.set_selection(&grid, start, end);
+    }
+
+    Ok(GridRenderer::new().render_layout(&layout)?)
+}
+
+/// Runs the render mode: parses arguments, renders and writes the PNG
+///
+/// # Arguments
+/// * `args` - Command-line arguments, without the program name and `render`
+pub fn run(args: &[String]) -> Result<(), PreviewError> {
+    let options = parse_args(args)?;
+    let pixmap = render_preview(&options)?;
+    let png = GridRenderer::new().encode_png(&pixmap)?;
+    std::fs::write(&options.output, png)?;
+
+    println!(
+        "Rendered {} overlay ({}x{}) to {}",
+        options.layout,
+        options.width,
+        options.height,
+        options.output.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// Set to regenerate reference images instead of comparing against them
+    const UPDATE_GOLDEN_VAR: &str = "TACTILE_UPDATE_GOLDEN";
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Compares a rendering against a reference image under tests/golden
+    ///
+    /// With `UPDATE_GOLDEN_VAR` set the reference is rewritten instead, and
+    /// the test still fails so an update run can never pass by accident.
+    fn assert_matches_golden(pixmap: &Pixmap, name: &str) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(name);
+
+        if std::env::var_os(UPDATE_GOLDEN_VAR).is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            pixmap.save_png(&path).unwrap();
+            panic!(
+                "Rewrote reference image {}; rerun without {} to compare",
+                path.display(),
+                UPDATE_GOLDEN_VAR
+            );
+        }
+
+        let golden = Pixmap::load_png(&path).unwrap_or_else(|e| {
+            panic!(
+                "Missing reference image {} ({}); rerun with {}=1",
+                path.display(),
+                e,
+                UPDATE_GOLDEN_VAR
+            )
+        });
+        assert_eq!(
+            (golden.width(), golden.height()),
+            (pixmap.width(), pixmap.height())
+        );
+        let differing = golden
+            .pixels()
+            .iter()
+            .zip(pixmap.pixels())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(differing, 0, "{} pixels differ from {}", differing, name);
+    }
+
+    #[test]
+    fn parses_full_command_line() {
+        let options = parse_args(&args(&[
+            "--width",
+            "2560",
+            "--height",
+            "1440",
+            "--layout",
+            "4x2",
+            "--active",
+            "--selection",
+            "q-s",
+            "--theme",
+            "light",
+            "out.png",
+        ]))
+        .unwrap();
+
+        assert_eq!(options.width, 2560);
+        assert_eq!(options.height, 1440);
+        assert_eq!(options.layout, GridConfig::new(4, 2));
+        assert!(options.active);
+        assert_eq!(options.selection, Some(KeyRange::new('Q', 'S')));
+        assert_eq!(options.theme, Theme::light());
+        assert_eq!(options.output, PathBuf::from("out.png"));
+    }
+
+    #[test]
+    fn missing_values_use_defaults() {
+        let options = parse_args(&args(&["out.png"])).unwrap();
+        assert_eq!(options, PreviewOptions::new("out.png"));
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(matches!(parse_args(&[]), Err(PreviewError::Usage(_))));
+        assert!(matches!(
+            parse_args(&args(&["--width", "wide", "out.png"])),
+            Err(PreviewError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(&args(&["--height"])),
+            Err(PreviewError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(&args(&["--bogus", "out.png"])),
+            Err(PreviewError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(&args(&["--layout", "4", "out.png"])),
+            Err(PreviewError::Layout(_))
+        ));
+        assert!(matches!(
+            parse_args(&args(&["--theme", "neon", "out.png"])),
+            Err(PreviewError::Usage(_))
+        ));
+        assert!(matches!(
+            parse_args(&args(&["a.png", "b.png"])),
+            Err(PreviewError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_keys_outside_the_grid() {
+        let mut options = PreviewOptions::new("out.png");
+        options.selection = Some(KeyRange::new('Q', 'P'));
+        assert!(matches!(
+            render_preview(&options),
+            Err(PreviewError::Grid(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_screens_too_small_for_the_grid() {
+        let mut options = PreviewOptions::new("out.png");
+        options.width = 800;
+        assert!(matches!(
+            render_preview(&options),
+            Err(PreviewError::Grid(GridError::ScreenTooSmall { .. }))
+        ));
+    }
+
+    #[test]
+    fn active_selection_matches_golden() {
+        let options = parse_args(&args(&[
+            "--layout",
+            "4x2",
+            "--active",
+            "--selection",
+            "Q-S",
+            "out.png",
+        ]))
+        .unwrap();
+        let pixmap = render_preview(&options).unwrap();
+        assert_matches_golden(&pixmap, "preview-4x2-active-q-s.png");
+    }
+
+    #[test]
+    fn inactive_default_grid_matches_golden() {
+        let pixmap = render_preview(&PreviewOptions::new("out.png")).unwrap();
+        assert_matches_golden(&pixmap, "preview-3x2-inactive.png");
+    }
+
+    #[test]
+    fn light_theme_matches_golden() {
+        let options = parse_args(&args(&[
+            "--layout",
+            "4x2",
+            "--active",
+            "--selection",
+            "W-D",
+            "--theme",
+            "light",
+            "out.png",
+        ]))
+        .unwrap();
+        let pixmap = render_preview(&options).unwrap();
+        assert_matches_golden(&pixmap, "preview-4x2-light-w-d.png");
+    }
+}
@@ -2,14 +2,23 @@
 //!
 //! Implements grid visualization with letter labels using tiny-skia for high-performance
 //! rendering. Separates layout calculation from rendering for better testability.
+//! Letters are rasterized with ab_glyph from the embedded IBM Plex Mono Bold font.
 
 use std::collections::HashMap;
 
-use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Rect as SkiaRect, Stroke, Transform};
+use ab_glyph::{Font, FontRef, PxScale, point};
+use tiny_skia::{
+    Color, ColorU8, FillRule, Paint, Path, PathBuilder, Pixmap, PixmapPaint, Rect as SkiaRect,
+    Stroke, Transform,
+};
 
 use crate::domain::core::Rect;
 use crate::domain::grid::Grid;
 use crate::domain::keyboard::GridCoords;
+use crate::ui::theme::Theme;
+
+/// Font used for cell letters, embedded so rendering never depends on installed fonts
+const LETTER_FONT: &[u8] = include_bytes!("../../assets/fonts/IBMPlexMono-Bold.ttf");
 
 /// Rendering errors
 #[derive(Debug, thiserror::Error)]
@@ -22,6 +31,9 @@ pub enum RendererError {
 
     #[error("Rendering operation failed")]
     RenderingFailed,
+
+    #[error("Failed to encode PNG: {0}")]
+    PngEncodingFailed(String),
 }
 
 /// Represents a single line segment for grid rendering
@@ -63,36 +75,85 @@ pub struct GridLayout {
     pub canvas_width: f32,
     pub canvas_height: f32,
 
+    /// Semi-transparent fill behind the grid
+    pub background: Color,
+
+    /// Highlighted selection area, if any
+    pub selection: Option<SkiaRect>,
+    pub selection_color: Color,
+    /// Color the selection switches to when it is rejected
+    pub error_color: Color,
+    /// Corner radius of the selection highlight in pixels
+    pub corner_radius: f32,
+
     /// Whether this layout is for an active monitor (shows letters)
     pub is_active: bool,
 }
 
 impl GridLayout {
-    /// Create a grid layout from domain Grid and keyboard layout
-    pub fn from_grid(grid: &Grid, canvas_rect: Rect, is_active: bool, dpi_scale: f32) -> Self {
+    /// Create a grid layout from domain Grid and keyboard layout, styled by `theme`
+    pub fn from_grid(
+        grid: &Grid,
+        canvas_rect: Rect,
+        is_active: bool,
+        dpi_scale: f32,
+        theme: &Theme,
+    ) -> Self {
         let mut layout = Self {
             lines: Vec::new(),
             letters: Vec::new(),
             canvas_width: canvas_rect.w as f32,
             canvas_height: canvas_rect.h as f32,
+            background: theme.background(is_active).to_color(),
+            selection: None,
+            selection_color: theme.selection.to_color(),
+            error_color: theme.error.to_color(),
+            corner_radius: (theme.corner_radius * dpi_scale).max(0.0),
             is_active,
         };
 
         // Calculate grid lines
-        layout.calculate_grid_lines(grid, canvas_rect, dpi_scale);
+        layout.calculate_grid_lines(grid, canvas_rect, dpi_scale, theme);
 
         // Calculate letter positions if active
         if is_active {
-            layout.calculate_letter_positions(grid, canvas_rect, dpi_scale);
+            layout.calculate_letter_positions(grid, canvas_rect, dpi_scale, theme);
         }
 
         layout
     }
 
+    /// Highlights the cells from `start` to `end` (inclusive, any corner order)
+    pub fn set_selection(&mut self, grid: &Grid, start: GridCoords, end: GridCoords) {
+        let (rows, cols) = grid.dimensions();
+        let cell_width = self.canvas_width / (cols as f32);
+        let cell_height = self.canvas_height / (rows as f32);
+
+        let (top, bottom) = (start.row.min(end.row), start.row.max(end.row));
+        let (left, right) = (start.col.min(end.col), start.col.max(end.col));
+        self.selection = SkiaRect::from_ltrb(
+            (left as f32) * cell_width,
+            (top as f32) * cell_height,
+            ((right + 1) as f32) * cell_width,
+            ((bottom + 1) as f32) * cell_height,
+        );
+    }
+
+    /// Shows the selection in the theme's error color (e.g. a rejected selection)
+    pub fn mark_selection_rejected(&mut self) {
+        self.selection_color = self.error_color;
+    }
+
     /// Calculate horizontal and vertical grid lines
-    fn calculate_grid_lines(&mut self, grid: &Grid, canvas_rect: Rect, dpi_scale: f32) {
-        let line_width = (2.0 * dpi_scale).max(1.0);
-        let line_color = Color::from_rgba8(255, 255, 255, 180); // Semi-transparent white
+    fn calculate_grid_lines(
+        &mut self,
+        grid: &Grid,
+        canvas_rect: Rect,
+        dpi_scale: f32,
+        theme: &Theme,
+    ) {
+        let line_width = (theme.line_width * dpi_scale).max(1.0);
+        let line_color = theme.grid_line.to_color();
 
         let (rows, cols) = grid.dimensions();
         let cell_width = (canvas_rect.w as f32) / (cols as f32);
@@ -126,17 +187,23 @@ impl GridLayout {
     }
 
     /// Calculate letter positions for keyboard layout
-    fn calculate_letter_positions(&mut self, grid: &Grid, canvas_rect: Rect, dpi_scale: f32) {
+    fn calculate_letter_positions(
+        &mut self,
+        grid: &Grid,
+        canvas_rect: Rect,
+        dpi_scale: f32,
+        theme: &Theme,
+    ) {
         let (rows, cols) = grid.dimensions();
         let cell_width = (canvas_rect.w as f32) / (cols as f32);
         let cell_height = (canvas_rect.h as f32) / (rows as f32);
-        let font_size = (32.0 * dpi_scale).max(24.0);
-        let letter_color = Color::from_rgba8(255, 255, 255, 255); // Fully opaque white
+        let font_size = (32.0 * dpi_scale).max(24.0) * theme.font_size_ratio;
+        let letter_color = theme.label.to_color();
 
         // Get all valid grid positions from keyboard layout
         for row in 0..rows {
             for col in 0..cols {
-                let coords = GridCoords::new(col, row);
+                let coords = GridCoords::new(row, col);
                 if let Ok(letter) = grid.key_for_coords(coords) {
                     // Calculate cell center
                     let cell_center_x = ((col as f32) + 0.5) * cell_width;
@@ -165,18 +232,30 @@ impl GridLayout {
     }
 }
 
+/// Rasterized glyph coverage, cropped to the glyph's ink bounds
+#[derive(Debug, Clone)]
+struct CachedGlyph {
+    width: u32,
+    height: u32,
+    /// Anti-aliased coverage per pixel (0 = none, 255 = full), row-major
+    coverage: Vec<u8>,
+}
+
 /// High-performance grid renderer using tiny-skia
 #[derive(Debug)]
 pub struct GridRenderer {
-    /// Cached font data for text rendering
-    font_cache: HashMap<u32, Vec<u8>>, // font_size -> font_data
+    /// Font used for cell letters
+    font: FontRef<'static>,
+    /// Rasterized glyphs, keyed by letter and font size (as f32 bits)
+    glyph_cache: HashMap<(char, u32), Option<CachedGlyph>>,
 }
 
 impl GridRenderer {
     /// Create a new grid renderer
     pub fn new() -> Self {
         Self {
-            font_cache: HashMap::new(),
+            font: FontRef::try_from_slice(LETTER_FONT).expect("embedded font is valid"),
+            glyph_cache: HashMap::new(),
         }
     }
 
@@ -186,8 +265,27 @@ impl GridRenderer {
         let mut pixmap = Pixmap::new(layout.canvas_width as u32, layout.canvas_height as u32)
             .ok_or(RendererError::PixmapCreationFailed)?;
 
-        // Clear with transparent background
-        pixmap.fill(Color::TRANSPARENT);
+        // Dim the desktop behind the grid
+        pixmap.fill(layout.background);
+
+        // Highlight the selection under the lines and letters
+        if let Some(selection) = layout.selection {
+            let mut paint = Paint::default();
+            paint.set_color(layout.selection_color);
+            if layout.corner_radius > 0.0 {
+                if let Some(path) = rounded_rect_path(selection, layout.corner_radius) {
+                    pixmap.fill_path(
+                        &path,
+                        &paint,
+                        FillRule::Winding,
+                        Transform::identity(),
+                        None,
+                    );
+                }
+            } else {
+                pixmap.fill_rect(selection, &paint, Transform::identity(), None);
+            }
+        }
 
         // Render grid lines
         self.render_lines(&mut pixmap, &layout.lines)?;
@@ -236,61 +334,143 @@ impl GridRenderer {
         Ok(())
     }
 
-    /// Render a single letter at the specified position
+    /// Render a single letter centered on its position
+    ///
+    /// The glyph's ink bounds are centered, so letters with and without
+    /// descenders sit in the middle of their cells.
     fn render_single_letter(
         &mut self,
         pixmap: &mut Pixmap,
         letter_pos: &LetterPosition,
     ) -> Result<(), RendererError> {
-        // For now, render a simple filled circle as a placeholder for the letter
-        // In a full implementation, we would use a font rasterizer like rusttype or ab_glyph
-        let radius = letter_pos.font_size / 4.0;
-
-        let mut path_builder = PathBuilder::new();
-        if SkiaRect::from_xywh(
-            letter_pos.x - radius,
-            letter_pos.y - radius,
-            radius * 2.0,
-            radius * 2.0,
-        )
-        .is_some()
-        {
-            path_builder.push_circle(letter_pos.x, letter_pos.y, radius);
+        let Some(glyph) = self.glyph(letter_pos.letter, letter_pos.font_size) else {
+            // Nothing to draw (e.g. the font has no outline for this character)
+            return Ok(());
+        };
 
-            if let Some(path) = path_builder.finish() {
-                let mut paint = Paint::default();
-                paint.set_color(letter_pos.color);
-
-                pixmap.fill_path(
-                    &path,
-                    &paint,
-                    FillRule::Winding,
-                    Transform::identity(),
-                    None,
-                );
-
-                // TODO: Add actual text rendering here
-                // This would require integrating a font library like rusttype
-                // For Phase 3, the circles serve as visual placeholders
-            }
+        let mut glyph_pixmap =
+            Pixmap::new(glyph.width, glyph.height).ok_or(RendererError::PixmapCreationFailed)?;
+        let color = letter_pos.color.to_color_u8();
+        for (pixel, &coverage) in glyph_pixmap.pixels_mut().iter_mut().zip(&glyph.coverage) {
+            let alpha = (u16::from(color.alpha()) * u16::from(coverage) / 255) as u8;
+            *pixel =
+                ColorU8::from_rgba(color.red(), color.green(), color.blue(), alpha).premultiply();
         }
 
+        let x = (letter_pos.x - glyph.width as f32 / 2.0).round() as i32;
+        let y = (letter_pos.y - glyph.height as f32 / 2.0).round() as i32;
+        pixmap.draw_pixmap(
+            x,
+            y,
+            glyph_pixmap.as_ref(),
+            &PixmapPaint::default(),
+            Transform::identity(),
+            None,
+        );
+
         Ok(())
     }
 
-    /// Convert pixmap to Win32 compatible bitmap data
-    /// Returns RGBA byte array suitable for Win32 display
+    /// Returns the rasterized glyph for a letter, rasterizing it on first use
+    fn glyph(&mut self, letter: char, font_size: f32) -> Option<&CachedGlyph> {
+        let font = &self.font;
+        self.glyph_cache
+            .entry((letter, font_size.to_bits()))
+            .or_insert_with(|| {
+                let glyph = font
+                    .glyph_id(letter)
+                    .with_scale_and_position(PxScale::from(font_size), point(0.0, 0.0));
+                let outlined = font.outline_glyph(glyph)?;
+                let bounds = outlined.px_bounds();
+                let width = bounds.width() as u32;
+                let height = bounds.height() as u32;
+                if width == 0 || height == 0 {
+                    return None;
+                }
+
+                let mut coverage = vec![0u8; (width * height) as usize];
+                outlined.draw(|x, y, c| {
+                    if x < width && y < height {
+                        coverage[(y * width + x) as usize] = (c.clamp(0.0, 1.0) * 255.0) as u8;
+                    }
+                });
+
+                Some(CachedGlyph {
+                    width,
+                    height,
+                    coverage,
+                })
+            })
+            .as_ref()
+    }
+
+    /// Convert pixmap to raw bitmap data
+    /// Returns premultiplied RGBA bytes (use `premultiplied_bgra` for Win32 DIBs)
     pub fn pixmap_to_rgba(&self, pixmap: &Pixmap) -> Vec<u8> {
-        // tiny-skia uses RGBA format, which is compatible with Win32
         pixmap.data().to_vec()
     }
 
+    /// Encode a rendered pixmap as PNG
+    ///
+    /// Works without a desktop, so renderings can be previewed and compared
+    /// against reference images on any platform.
+    pub fn encode_png(&self, pixmap: &Pixmap) -> Result<Vec<u8>, RendererError> {
+        pixmap
+            .encode_png()
+            .map_err(|e| RendererError::PngEncodingFailed(e.to_string()))
+    }
+
     /// Get pixmap dimensions
     pub fn get_pixmap_size(&self, pixmap: &Pixmap) -> (u32, u32) {
         (pixmap.width(), pixmap.height())
     }
 }
 
+/// Builds a rectangle path with rounded corners
+///
+/// The radius is clamped to half the shorter side. Corners are cubic
+/// approximations of quarter circles.
+fn rounded_rect_path(rect: SkiaRect, radius: f32) -> Option<Path> {
+    // Control point distance for a cubic approximating a quarter circle
+    const KAPPA: f32 = 0.552_284_8;
+
+    let r = radius.min(rect.width() / 2.0).min(rect.height() / 2.0);
+    let k = r * KAPPA;
+    let (left, top, right, bottom) = (rect.left(), rect.top(), rect.right(), rect.bottom());
+
+    let mut pb = PathBuilder::new();
+    pb.move_to(left + r, top);
+    pb.line_to(right - r, top);
+    pb.cubic_to(right - r + k, top, right, top + r - k, right, top + r);
+    pb.line_to(right, bottom - r);
+    pb.cubic_to(
+        right,
+        bottom - r + k,
+        right - r + k,
+        bottom,
+        right - r,
+        bottom,
+    );
+    pb.line_to(left + r, bottom);
+    pb.cubic_to(left + r - k, bottom, left, bottom - r + k, left, bottom - r);
+    pb.line_to(left, top + r);
+    pb.cubic_to(left, top + r - k, left + r - k, top, left + r, top);
+    pb.close();
+    pb.finish()
+}
+
+/// Converts a pixmap to the premultiplied BGRA layout of 32-bit Win32 DIB sections
+///
+/// tiny-skia stores premultiplied RGBA; UpdateLayeredWindow expects
+/// premultiplied BGRA, so the red and blue channels are swapped.
+pub fn premultiplied_bgra(pixmap: &Pixmap) -> Vec<u8> {
+    let mut data = pixmap.data().to_vec();
+    for pixel in data.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+    data
+}
+
 impl Default for GridRenderer {
     fn default() -> Self {
         Self::new()
@@ -300,7 +480,6 @@ impl Default for GridRenderer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::keyboard::QwertyLayout;
 
     #[test]
     fn grid_layout_creation() {
@@ -322,7 +501,7 @@ mod tests {
             h: 1080,
         };
 
-        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, true, 1.0);
+        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, true, 1.0, &Theme::default());
 
         // Should have vertical and horizontal lines
         assert!(!grid_layout.lines.is_empty(), "Grid should have lines");
@@ -358,7 +537,7 @@ mod tests {
             h: 1080,
         };
 
-        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, false, 1.0);
+        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, false, 1.0, &Theme::default());
 
         // Should have lines but no letters when inactive
         assert!(!grid_layout.lines.is_empty(), "Grid should have lines");
@@ -388,8 +567,8 @@ mod tests {
             h: 1080,
         };
 
-        let normal_layout = GridLayout::from_grid(&grid, canvas_rect, true, 1.0);
-        let scaled_layout = GridLayout::from_grid(&grid, canvas_rect, true, 2.0);
+        let normal_layout = GridLayout::from_grid(&grid, canvas_rect, true, 1.0, &Theme::default());
+        let scaled_layout = GridLayout::from_grid(&grid, canvas_rect, true, 2.0, &Theme::default());
 
         // Line width should scale with DPI
         if let (Some(normal_line), Some(scaled_line)) =
@@ -412,10 +591,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn letters_follow_keyboard_rows_in_portrait_grid() {
+        // Portrait 2 cols x 3 rows: Q W / A S / Z X, top to bottom
+        let area = Rect::new(0, 0, 1080, 1880);
+        let grid = Grid::new(3, 2, area).unwrap();
+
+        let layout = GridLayout::from_grid(&grid, area, true, 1.0, &Theme::default());
+        let letters: Vec<char> = layout.letters.iter().map(|l| l.letter).collect();
+        assert_eq!(letters, vec!['Q', 'W', 'A', 'S', 'Z', 'X']);
+
+        // 'A' sits in the left column of the middle row
+        let a = &layout.letters[2];
+        assert!(a.x < 540.0);
+        assert!(a.y > 1880.0 / 3.0 && a.y < 2.0 * 1880.0 / 3.0);
+    }
+
     #[test]
     fn grid_renderer_creation() {
         let renderer = GridRenderer::new();
-        assert!(renderer.font_cache.is_empty());
+        assert!(renderer.glyph_cache.is_empty());
+    }
+
+    /// Bounding box (min x, min y, max x, max y) of pixels with any coverage
+    fn ink_bounds(pixmap: &Pixmap) -> Option<(u32, u32, u32, u32)> {
+        let mut bounds: Option<(u32, u32, u32, u32)> = None;
+        for (i, pixel) in pixmap.pixels().iter().enumerate() {
+            if pixel.alpha() == 0 {
+                continue;
+            }
+            let (x, y) = (i as u32 % pixmap.width(), i as u32 / pixmap.width());
+            bounds = Some(match bounds {
+                None => (x, y, x, y),
+                Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+            });
+        }
+        bounds
+    }
+
+    fn letter_at(letter: char, x: f32, y: f32) -> LetterPosition {
+        LetterPosition {
+            letter,
+            x,
+            y,
+            font_size: 32.0,
+            color: Color::WHITE,
+            cell_rect: SkiaRect::from_xywh(0.0, 0.0, 200.0, 200.0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn letters_are_drawn_centered_on_their_position() {
+        let mut renderer = GridRenderer::new();
+        for letter in ['Q', 'W', 'A', 'J'] {
+            let mut pixmap = Pixmap::new(200, 200).unwrap();
+            renderer
+                .render_single_letter(&mut pixmap, &letter_at(letter, 100.0, 80.0))
+                .unwrap();
+
+            let (x0, y0, x1, y1) = ink_bounds(&pixmap).expect("letter should be drawn");
+            let center_x = (x0 + x1 + 1) as f32 / 2.0;
+            let center_y = (y0 + y1 + 1) as f32 / 2.0;
+            assert!((center_x - 100.0).abs() <= 1.0, "{}: {}", letter, center_x);
+            assert!((center_y - 80.0).abs() <= 1.0, "{}: {}", letter, center_y);
+
+            // A real glyph, not a placeholder: roughly cap height for a 32px font
+            let height = y1 - y0 + 1;
+            assert!((18..=30).contains(&height), "{}: {}", letter, height);
+        }
+    }
+
+    #[test]
+    fn letters_are_anti_aliased() {
+        let mut renderer = GridRenderer::new();
+        let mut pixmap = Pixmap::new(100, 100).unwrap();
+        renderer
+            .render_single_letter(&mut pixmap, &letter_at('S', 50.0, 50.0))
+            .unwrap();
+
+        let alphas: Vec<u8> = pixmap.pixels().iter().map(|p| p.alpha()).collect();
+        assert!(alphas.contains(&255), "glyph should have solid pixels");
+        assert!(
+            alphas.iter().any(|&a| a > 0 && a < 255),
+            "glyph edges should be partially covered"
+        );
+    }
+
+    #[test]
+    fn glyphs_are_cached_per_letter_and_size() {
+        let mut renderer = GridRenderer::new();
+        let mut pixmap = Pixmap::new(100, 100).unwrap();
+        let mut big = letter_at('Q', 50.0, 50.0);
+        big.font_size = 48.0;
+
+        renderer
+            .render_single_letter(&mut pixmap, &letter_at('Q', 50.0, 50.0))
+            .unwrap();
+        renderer
+            .render_single_letter(&mut pixmap, &letter_at('Q', 20.0, 20.0))
+            .unwrap();
+        assert_eq!(renderer.glyph_cache.len(), 1);
+
+        renderer.render_single_letter(&mut pixmap, &big).unwrap();
+        assert_eq!(renderer.glyph_cache.len(), 2);
+    }
+
+    #[test]
+    fn different_letters_render_differently() {
+        let mut renderer = GridRenderer::new();
+        let mut render = |letter| {
+            let mut pixmap = Pixmap::new(60, 60).unwrap();
+            renderer
+                .render_single_letter(&mut pixmap, &letter_at(letter, 30.0, 30.0))
+                .unwrap();
+            pixmap.data().to_vec()
+        };
+        assert_ne!(render('Q'), render('W'));
     }
 
     #[test]
@@ -439,7 +730,7 @@ mod tests {
             h: 800,
         };
 
-        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, true, 1.0);
+        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, true, 1.0, &Theme::default());
 
         // Should be able to render without error
         let result = renderer.render_layout(&grid_layout);
@@ -452,6 +743,126 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rendered_pixels_follow_layout() {
+        let mut renderer = GridRenderer::new();
+        let area = Rect::new(0, 0, 1920, 1080);
+        let grid = Grid::new(2, 3, area).unwrap();
+        let layout = GridLayout::from_grid(&grid, area, false, 1.0, &Theme::default());
+        let pixmap = renderer.render_layout(&layout).unwrap();
+
+        // Away from the lines only the background is visible
+        let background = layout.background.premultiply().to_color_u8();
+        let pixel = pixmap.pixel(150, 150).unwrap();
+        assert_eq!(pixel, background);
+
+        // Every layout line is drawn where the layout puts it
+        for line in &layout.lines {
+            let x = ((line.x1 + line.x2) / 2.0) as u32;
+            let y = ((line.y1 + line.y2) / 2.0) as u32;
+            let pixel = pixmap.pixel(x, y).unwrap();
+            assert_ne!(pixel, background, "line at ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn selection_is_highlighted() {
+        let mut renderer = GridRenderer::new();
+        let area = Rect::new(0, 0, 1920, 1080);
+        let grid = Grid::new(2, 3, area).unwrap();
+        let mut layout = GridLayout::from_grid(&grid, area, false, 1.0, &Theme::default());
+        // Corners in any order cover the same cells
+        layout.set_selection(&grid, GridCoords::new(1, 1), GridCoords::new(0, 0));
+        assert_eq!(
+            layout.selection,
+            SkiaRect::from_ltrb(0.0, 0.0, 1280.0, 1080.0)
+        );
+
+        let pixmap = renderer.render_layout(&layout).unwrap();
+        let background = layout.background.premultiply().to_color_u8();
+        assert_ne!(pixmap.pixel(100, 100).unwrap(), background);
+        assert_eq!(pixmap.pixel(1500, 100).unwrap(), background);
+    }
+
+    #[test]
+    fn layout_follows_theme() {
+        let area = Rect::new(0, 0, 1920, 1080);
+        let grid = Grid::new(2, 3, area).unwrap();
+        let theme = Theme::high_contrast();
+
+        let active = GridLayout::from_grid(&grid, area, true, 2.0, &theme);
+        assert_eq!(active.background, theme.active_background.to_color());
+        assert!(active.lines.iter().all(|line| line.width == 6.0));
+        assert!(
+            active
+                .lines
+                .iter()
+                .all(|line| line.color == theme.grid_line.to_color())
+        );
+        assert!(
+            active.letters.iter().all(|letter| {
+                letter.font_size == 80.0 && letter.color == theme.label.to_color()
+            })
+        );
+
+        let inactive = GridLayout::from_grid(&grid, area, false, 1.0, &theme);
+        assert_eq!(inactive.background, theme.inactive_background.to_color());
+    }
+
+    #[test]
+    fn rejected_selection_uses_error_color() {
+        let area = Rect::new(0, 0, 1920, 1080);
+        let grid = Grid::new(2, 3, area).unwrap();
+        let theme = Theme::default();
+        let mut layout = GridLayout::from_grid(&grid, area, true, 1.0, &theme);
+        layout.set_selection(&grid, GridCoords::new(0, 0), GridCoords::new(0, 0));
+        assert_eq!(layout.selection_color, theme.selection.to_color());
+
+        layout.mark_selection_rejected();
+        assert_eq!(layout.selection_color, theme.error.to_color());
+    }
+
+    #[test]
+    fn selection_corners_are_rounded() {
+        let mut renderer = GridRenderer::new();
+        let area = Rect::new(0, 0, 1920, 1080);
+        let grid = Grid::new(2, 3, area).unwrap();
+        let theme = Theme {
+            corner_radius: 20.0,
+            ..Theme::default()
+        };
+        let mut layout = GridLayout::from_grid(&grid, area, false, 1.0, &theme);
+        layout.set_selection(&grid, GridCoords::new(0, 1), GridCoords::new(0, 1));
+
+        let pixmap = renderer.render_layout(&layout).unwrap();
+        let background = layout.background.premultiply().to_color_u8();
+        // The corner pixel stays background, the edge midpoint is highlighted
+        assert_eq!(pixmap.pixel(641, 1).unwrap(), background);
+        assert_ne!(pixmap.pixel(960, 10).unwrap(), background);
+    }
+
+    #[test]
+    fn rendering_encodes_to_png() {
+        let mut renderer = GridRenderer::new();
+        let area = Rect::new(0, 0, 1000, 800);
+        let grid = Grid::new(2, 2, area).unwrap();
+        let layout = GridLayout::from_grid(&grid, area, true, 1.0, &Theme::default());
+        let pixmap = renderer.render_layout(&layout).unwrap();
+
+        let png = renderer.encode_png(&pixmap).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        assert_eq!(Pixmap::decode_png(&png).unwrap(), pixmap);
+    }
+
+    #[test]
+    fn bgra_conversion_swaps_red_and_blue() {
+        let mut pixmap = Pixmap::new(2, 1).unwrap();
+        pixmap.fill(Color::from_rgba8(255, 0, 0, 255));
+
+        let bgra = premultiplied_bgra(&pixmap);
+        assert_eq!(bgra, vec![0, 0, 255, 255, 0, 0, 255, 255]);
+    }
+
     #[test]
     fn pixmap_to_rgba_conversion() {
         let mut renderer = GridRenderer::new();
@@ -473,7 +884,7 @@ mod tests {
             h: 800,
         };
 
-        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, false, 1.0); // inactive for simplicity
+        let grid_layout = GridLayout::from_grid(&grid, canvas_rect, false, 1.0, &Theme::default()); // inactive for simplicity
 
         if let Ok(pixmap) = renderer.render_layout(&grid_layout) {
             let rgba_data = renderer.pixmap_to_rgba(&pixmap);
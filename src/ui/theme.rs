@@ -0,0 +1,244 @@
+//! Overlay themes
+//!
+//! A theme holds every color and size the overlay renderer uses, so the
+//! grid's look can be changed from the config file. A few themes are built
+//! in; users can define their own under `themes` and select one by name.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tiny_skia::Color;
+
+/// Names of the built-in themes
+pub const BUILTIN_THEMES: &[&str] = &["default", "high_contrast", "light"];
+
+/// Errors that can occur while parsing theme values
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ThemeError {
+    #[error("Invalid color '{0}' (expected \"#RRGGBB\" or \"#RRGGBBAA\")")]
+    InvalidColor(String),
+}
+
+/// RGBA color, written as "#RRGGBB" or "#RRGGBBAA" in the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ThemeColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+}
+
+impl ThemeColor {
+    /// Creates a color from its components
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Converts to a tiny-skia color for rendering
+    pub fn to_color(self) -> Color {
+        Color::from_rgba8(self.red, self.green, self.blue, self.alpha)
+    }
+}
+
+impl FromStr for ThemeColor {
+    type Err = ThemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ThemeError::InvalidColor(s.to_string());
+        let hex = s.trim().strip_prefix('#').ok_or_else(invalid)?;
+        if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+            return Err(invalid());
+        }
+
+        let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+        let alpha = if hex.len() == 8 { component(6)? } else { 255 };
+        Ok(Self::rgba(
+            component(0)?,
+            component(2)?,
+            component(4)?,
+            alpha,
+        ))
+    }
+}
+
+impl TryFrom<String> for ThemeColor {
+    type Error = ThemeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ThemeColor> for String {
+    fn from(color: ThemeColor) -> Self {
+        color.to_string()
+    }
+}
+
+impl std::fmt::Display for ThemeColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.red, self.green, self.blue, self.alpha
+        )
+    }
+}
+
+/// Colors and sizes used to draw the grid overlay
+///
+/// Fields missing from a user theme keep the default theme's values.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Fill behind the grid on the monitor currently taking keys
+    pub active_background: ThemeColor,
+    /// Fill behind the grid on the other monitors
+    pub inactive_background: ThemeColor,
+    /// Lines between cells
+    pub grid_line: ThemeColor,
+    /// Cell letters
+    pub label: ThemeColor,
+    /// Highlight of the selected cells
+    pub selection: ThemeColor,
+    /// Highlight of a rejected selection
+    pub error: ThemeColor,
+    /// Grid line width in pixels at 100% scaling
+    pub line_width: f32,
+    /// Letter size relative to the default size (1.0 = 32px at 100% scaling)
+    pub font_size_ratio: f32,
+    /// Corner radius of the selection highlight in pixels at 100% scaling
+    pub corner_radius: f32,
+}
+
+impl Default for Theme {
+    /// Dark blue overlay with white lines and letters
+    fn default() -> Self {
+        Self {
+            active_background: ThemeColor::rgba(0, 0, 51, 180),
+            inactive_background: ThemeColor::rgba(0, 0, 51, 180),
+            grid_line: ThemeColor::rgba(255, 255, 255, 180),
+            label: ThemeColor::rgba(255, 255, 255, 255),
+            selection: ThemeColor::rgba(70, 130, 230, 200),
+            error: ThemeColor::rgba(220, 50, 50, 200),
+            line_width: 2.0,
+            font_size_ratio: 1.0,
+            corner_radius: 0.0,
+        }
+    }
+}
+
+impl Theme {
+    /// Black overlay with bright yellow lines and larger letters
+    pub fn high_contrast() -> Self {
+        Self {
+            active_background: ThemeColor::rgba(0, 0, 0, 230),
+            inactive_background: ThemeColor::rgba(0, 0, 0, 200),
+            grid_line: ThemeColor::rgba(255, 255, 0, 255),
+            label: ThemeColor::rgba(255, 255, 0, 255),
+            selection: ThemeColor::rgba(0, 90, 200, 230),
+            error: ThemeColor::rgba(230, 0, 0, 230),
+            line_width: 3.0,
+            font_size_ratio: 1.25,
+            corner_radius: 0.0,
+        }
+    }
+
+    /// Pale overlay with dark lines and letters
+    pub fn light() -> Self {
+        Self {
+            active_background: ThemeColor::rgba(245, 245, 245, 190),
+            inactive_background: ThemeColor::rgba(245, 245, 245, 140),
+            grid_line: ThemeColor::rgba(40, 40, 40, 160),
+            label: ThemeColor::rgba(20, 20, 20, 255),
+            selection: ThemeColor::rgba(0, 120, 215, 120),
+            error: ThemeColor::rgba(200, 40, 40, 160),
+            line_width: 2.0,
+            font_size_ratio: 1.0,
+            corner_radius: 8.0,
+        }
+    }
+
+    /// Looks up a built-in theme by name (see `BUILTIN_THEMES`)
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "high_contrast" => Some(Self::high_contrast()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    /// Returns the background fill for an active or inactive overlay
+    pub fn background(&self, is_active: bool) -> ThemeColor {
+        if is_active {
+            self.active_background
+        } else {
+            self.inactive_background
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_parsing() {
+        assert_eq!(
+            "#FF8000".parse::<ThemeColor>().unwrap(),
+            ThemeColor::rgba(255, 128, 0, 255)
+        );
+        assert_eq!(
+            " #00003380 ".parse::<ThemeColor>().unwrap(),
+            ThemeColor::rgba(0, 0, 51, 128)
+        );
+
+        assert!("FF8000".parse::<ThemeColor>().is_err());
+        assert!("#FF80".parse::<ThemeColor>().is_err());
+        assert!("#GG8000".parse::<ThemeColor>().is_err());
+        assert!("#ÿÿÿ".parse::<ThemeColor>().is_err());
+    }
+
+    #[test]
+    fn color_round_trips_as_string() {
+        let json = serde_json::to_string(&ThemeColor::rgba(0, 0, 51, 180)).unwrap();
+        assert_eq!(json, "\"#000033B4\"");
+        assert_eq!(
+            serde_json::from_str::<ThemeColor>(&json).unwrap(),
+            ThemeColor::rgba(0, 0, 51, 180)
+        );
+    }
+
+    #[test]
+    fn every_builtin_theme_resolves() {
+        for name in BUILTIN_THEMES {
+            assert!(Theme::builtin(name).is_some(), "{} is missing", name);
+        }
+        assert_eq!(Theme::builtin("default"), Some(Theme::default()));
+        assert_eq!(Theme::builtin("neon"), None);
+    }
+
+    #[test]
+    fn partial_theme_keeps_defaults() {
+        let json = r##"{ "label": "#FFCC00", "corner_radius": 6.0 }"##;
+        let theme: Theme = serde_json::from_str(json).unwrap();
+        assert_eq!(theme.label, ThemeColor::rgba(255, 204, 0, 255));
+        assert_eq!(theme.corner_radius, 6.0);
+        assert_eq!(theme.grid_line, Theme::default().grid_line);
+        assert_eq!(theme.line_width, Theme::default().line_width);
+    }
+
+    #[test]
+    fn background_follows_activity() {
+        let theme = Theme::light();
+        assert_eq!(theme.background(true), theme.active_background);
+        assert_eq!(theme.background(false), theme.inactive_background);
+    }
+}